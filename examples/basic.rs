@@ -49,7 +49,9 @@ async fn main() {
 
     let token = std::env::var("BOT_TOKEN").expect("Missing Env Variable: BOT_TOKEN");
     let http = Http::new(&token, true);
-    let ws = WebSocket::connect(&token).await;
+    let ws = WebSocket::connect(&token)
+        .await
+        .expect("Failed to authenticate");
     let cache = Cache::new();
 
     let bot = Bot {
@@ -0,0 +1,51 @@
+use crate::{
+    DEFAULT_API_URL, Http,
+    error::HttpError,
+    routes::auth::session_login::{Login, LoginResponse, MfaMethod, Session},
+};
+
+/// Outcome of [Http::login].
+#[derive(Clone)]
+pub enum LoginResult {
+    /// Logged in, `http` is authenticated with the new session's token.
+    Success { http: Http, session: Session },
+    /// The account has MFA enabled.
+    ///
+    /// Log in again with [Login::mfa] using this ticket and one of the allowed methods.
+    Mfa {
+        ticket: String,
+        allowed_methods: Vec<MfaMethod>,
+    },
+    /// The account has been disabled.
+    Disabled { user_id: String },
+}
+
+impl Http {
+    /// Logs in without an existing session token.
+    pub async fn login(data: impl Into<Login>) -> Result<LoginResult, HttpError> {
+        Self::login_with_api_url(DEFAULT_API_URL, data).await
+    }
+
+    pub async fn login_with_api_url(
+        api_url: impl std::fmt::Display,
+        data: impl Into<Login>,
+    ) -> Result<LoginResult, HttpError> {
+        let api_url = api_url.to_string();
+        let response = Self::unauthenticated(&api_url).create_session(data).await?;
+        let result = match response {
+            LoginResponse::Success(session) => LoginResult::Success {
+                http: Self::with_api_url(api_url, &session.token, false),
+                session,
+            },
+            LoginResponse::Mfa {
+                ticket,
+                allowed_methods,
+            } => LoginResult::Mfa {
+                ticket,
+                allowed_methods,
+            },
+            LoginResponse::Disabled { user_id } => LoginResult::Disabled { user_id },
+        };
+        Ok(result)
+    }
+}
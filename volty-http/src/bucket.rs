@@ -10,6 +10,7 @@ use reqwest::{Method, Response};
 pub enum BucketKey {
     Auth,
     AuthDelete,
+    Autumn,
     Bots,
     Channels(String),
     DefaultAvatar,
@@ -56,6 +57,7 @@ impl BucketKey {
         match self {
             BucketKey::Auth => 15,
             BucketKey::AuthDelete => 255,
+            BucketKey::Autumn => 20,
             BucketKey::Bots => 10,
             BucketKey::Channels(_) => 15,
             BucketKey::DefaultAvatar => 255,
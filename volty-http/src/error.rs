@@ -232,6 +232,7 @@ pub enum HttpError {
     Api(ApiError),
     Reqwest(Arc<reqwest::Error>),
     Serde(Arc<serde_json::Error>),
+    Io(Arc<std::io::Error>),
 }
 
 impl From<ApiError> for HttpError {
@@ -269,3 +270,15 @@ impl From<ValidationErrors> for HttpError {
         HttpError::Api(value.into())
     }
 }
+
+impl From<std::io::Error> for HttpError {
+    fn from(value: std::io::Error) -> Self {
+        HttpError::Io(Arc::new(value))
+    }
+}
+
+impl From<Arc<std::io::Error>> for HttpError {
+    fn from(value: Arc<std::io::Error>) -> Self {
+        HttpError::Io(value)
+    }
+}
@@ -0,0 +1,42 @@
+use reqwest::Method;
+use volty_types::{RevoltConfig, types::january::Embed};
+
+use crate::{Http, encode_segment, error::HttpError, retry_after};
+
+/// Client for January, the service that unfurls links and proxies remote media.
+#[derive(Clone)]
+pub struct JanuaryClient {
+    http: Http,
+    url: String,
+}
+
+impl JanuaryClient {
+    pub fn new(january_url: impl std::fmt::Display) -> Self {
+        let url = january_url.to_string().trim_end_matches('/').to_string();
+        Self {
+            http: Http::unauthenticated(&url),
+            url,
+        }
+    }
+
+    /// Returns `None` if January is disabled on this instance.
+    pub fn from_config(config: &RevoltConfig) -> Option<Self> {
+        let january = &config.features.january;
+        january.enabled.then(|| Self::new(&january.url))
+    }
+
+    /// URL through which January serves the given remote image or video.
+    pub fn proxy_url(&self, url: impl std::fmt::Display) -> String {
+        format!("{}/proxy?url={}", self.url, encode_segment(url))
+    }
+
+    /// Generates the same embed the official clients show for a link.
+    pub async fn unfurl(&self, url: impl std::fmt::Display) -> Result<Embed, HttpError> {
+        let query = [("url", url.to_string())];
+        retry_after(|| async {
+            let request = self.http.request(Method::GET, "embed")?.query(&query);
+            self.http.send_request(request).await
+        })
+        .await
+    }
+}
@@ -1,19 +1,30 @@
-use std::{collections::HashMap, ops::Deref, sync::Arc, time::Duration};
+use std::{collections::HashMap, future::Future, ops::Deref, sync::Arc, time::Duration};
 
 use bucket::{BucketKey, Buckets};
 use error::HttpError;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
 use reqwest::{
     Method, RequestBuilder,
-    header::{HeaderMap, HeaderValue},
+    header::{HeaderMap, HeaderName, HeaderValue},
+    multipart::Form,
 };
+use routes::autumn::autumn_info::AutumnInfo;
 use serde::{Serialize, de::DeserializeOwned};
+use tokio::sync::OnceCell;
 use volty_types::RevoltConfig;
 
+mod auth;
 mod bucket;
 pub mod error;
+mod january;
+mod pager;
 pub mod routes;
+mod webhook;
 
+pub use auth::LoginResult;
 pub use error::ApiError;
+pub use january::JanuaryClient;
+pub use webhook::WebhookClient;
 
 #[derive(Clone)]
 pub struct Http {
@@ -30,6 +41,9 @@ impl Deref for Http {
 
 pub struct InnerHttp {
     api_url: String,
+    /// fetched from the API on first use
+    autumn_url: OnceCell<String>,
+    autumn_info: OnceCell<AutumnInfo>,
 
     buckets: Buckets,
     /// all requests will contain token
@@ -46,11 +60,50 @@ impl Request {
         self.request = self.request.json(json);
         self
     }
+
+    pub fn query<Q: Serialize>(mut self, query: &Q) -> Self {
+        self.request = self.request.query(query);
+        self
+    }
+
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.request = self.request.header(key, value);
+        self
+    }
+
+    pub fn multipart(mut self, form: Form) -> Self {
+        self.request = self.request.multipart(form);
+        self
+    }
 }
 
+/// Percent-encodes a value so it can be used as a single path segment.
+pub(crate) fn encode_segment(segment: impl std::fmt::Display) -> String {
+    utf8_percent_encode(&segment.to_string(), NON_ALPHANUMERIC).to_string()
+}
+
+/// Calls `f` until it no longer fails with [ApiError::RetryAfter],
+/// sleeping for the requested duration in between.
+pub(crate) async fn retry_after<T, F, Fut>(mut f: F) -> Result<T, HttpError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, HttpError>>,
+{
+    loop {
+        match f().await {
+            Err(HttpError::Api(ApiError::RetryAfter(duration))) => {
+                log::debug!("Retrying after: {:?}", duration);
+                tokio::time::sleep(duration).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+const DEFAULT_API_URL: &str = "https://api.stoat.chat/";
+
 impl Http {
     pub fn new(token: impl std::fmt::Display, is_bot: bool) -> Self {
-        const DEFAULT_API_URL: &str = "https://api.stoat.chat/";
         Self::with_api_url(DEFAULT_API_URL, token, is_bot)
     }
 
@@ -68,15 +121,25 @@ impl Http {
             },
             HeaderValue::from_str(&token.to_string()).unwrap(),
         );
-        rustls::crypto::ring::default_provider()
-            .install_default()
-            .unwrap();
+        Self::with_headers(api_url, headers)
+    }
+
+    /// Client without an auth token, for routes authenticated by other means.
+    pub(crate) fn unauthenticated(api_url: impl std::fmt::Display) -> Self {
+        Self::with_headers(api_url, HeaderMap::new())
+    }
+
+    fn with_headers(api_url: impl std::fmt::Display, headers: HeaderMap) -> Self {
+        // Fails if a provider was already installed, e.g. by another client.
+        let _ = rustls::crypto::ring::default_provider().install_default();
         let client = reqwest::Client::builder()
             .default_headers(headers)
             .build()
             .unwrap();
         let inner = InnerHttp {
             api_url: api_url.to_string(),
+            autumn_url: OnceCell::new(),
+            autumn_info: OnceCell::new(),
             buckets: Buckets::new(),
             client,
         };
@@ -95,28 +158,64 @@ impl Http {
         Ok(Request { bucket, request })
     }
 
+    /// Request to a full file server URL, counted against the autumn bucket.
+    pub(crate) fn autumn_request(&self, method: Method, url: &str) -> Result<Request, HttpError> {
+        let bucket = BucketKey::Autumn;
+        if let Err(e) = self.buckets.take(&bucket) {
+            return Err(ApiError::RetryAfter(e).into());
+        }
+        let request = self.client.request(method, url);
+        Ok(Request { bucket, request })
+    }
+
     async fn send_request<T: DeserializeOwned>(&self, request: Request) -> Result<T, HttpError> {
         log::debug!("Request: {:?}", &request.request);
         let response = request.request.send().await;
         self.handle_response(response, request.bucket).await
     }
 
+    /// Like [Self::send_request], but returns the raw response body.
+    async fn send_request_bytes(&self, request: Request) -> Result<Vec<u8>, HttpError> {
+        let response = self.send_request_raw(request).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Like [Self::send_request], but leaves reading the body to the caller.
+    async fn send_request_raw(&self, request: Request) -> Result<reqwest::Response, HttpError> {
+        log::debug!("Request: {:?}", &request.request);
+        let response = request.request.send().await;
+        self.check_response(response, request.bucket).await
+    }
+
     async fn handle_response<T: DeserializeOwned>(
         &self,
         response: Result<reqwest::Response, reqwest::Error>,
         bucket: BucketKey,
     ) -> Result<T, HttpError> {
+        let response = self.check_response(response, bucket).await?;
+        let text = response.text().await?;
+        // 204 No Content responses are treated as `null`.
+        let text = if text.is_empty() { "null" } else { &text };
+        let t = serde_json::from_str(text)?;
+        Ok(t)
+    }
+
+    /// Updates the bucket and turns unsuccessful responses into errors.
+    async fn check_response(
+        &self,
+        response: Result<reqwest::Response, reqwest::Error>,
+        bucket: BucketKey,
+    ) -> Result<reqwest::Response, HttpError> {
         match response {
             Ok(response) => {
                 self.buckets.handle_response(&bucket, &response);
-                let is_success = response.status().is_success();
                 let status_code = response.status().as_u16();
+                if response.status().is_success() {
+                    return Ok(response);
+                }
                 let text = response.text().await?;
 
-                if is_success {
-                    let t = serde_json::from_str(&text)?;
-                    Ok(t)
-                } else if status_code == 429 {
+                if status_code == 429 {
                     let millis = serde_json::from_str::<HashMap<String, u64>>(&text)
                         .map(|m| *m.get("retry_after").unwrap_or(&10_000))
                         .unwrap_or(10_000);
@@ -140,4 +239,24 @@ impl Http {
         let request = self.request(Method::GET, "")?;
         self.send_request(request).await
     }
+
+    /// Base URL of this instance's file server, from [Http::api_info].
+    pub async fn autumn_url(&self) -> Result<&str, HttpError> {
+        let url = self
+            .autumn_url
+            .get_or_try_init(|| async {
+                let config = self.api_info().await?;
+                let url = config.features.autumn.url;
+                Ok::<_, HttpError>(url.trim_end_matches('/').to_string())
+            })
+            .await?;
+        Ok(url)
+    }
+
+    /// File server configuration, fetched once and then cached.
+    pub async fn autumn_info(&self) -> Result<&AutumnInfo, HttpError> {
+        self.autumn_info
+            .get_or_try_init(|| self.fetch_autumn_info())
+            .await
+    }
 }
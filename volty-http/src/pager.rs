@@ -0,0 +1,58 @@
+use std::{future::Future, sync::Arc};
+
+use futures::stream::{self, BoxStream, StreamExt};
+use volty_types::channels::message::{BulkMessageResponse, Message};
+
+use crate::{Http, error::HttpError, retry_after};
+
+/// Default page size used by the API when no limit is given
+pub(crate) const DEFAULT_LIMIT: i64 = 50;
+
+/// Walks message pages of a channel until a short or empty page.
+///
+/// `advance` moves the query past the last message of a full page
+/// and returns `false` if the results cannot be paged any further.
+/// Rate limits are waited out instead of returned.
+pub(crate) fn message_pages<Q, F, Fut, A>(
+    http: &Http,
+    channel_id: impl std::fmt::Display,
+    data: Q,
+    limit: i64,
+    fetch: F,
+    advance: A,
+) -> BoxStream<'static, Result<BulkMessageResponse, HttpError>>
+where
+    Q: Clone + Send + Sync + 'static,
+    F: Fn(Http, String, Q) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<BulkMessageResponse, HttpError>> + Send,
+    A: Fn(&mut Q, &Message) -> bool + Send + Sync + 'static,
+{
+    let fetch = Arc::new(fetch);
+    let advance = Arc::new(advance);
+    let state = Some((http.clone(), channel_id.to_string(), data));
+    stream::unfold(state, move |state| {
+        let fetch = fetch.clone();
+        let advance = advance.clone();
+        async move {
+            let (http, channel_id, mut data) = state?;
+            let result =
+                retry_after(|| fetch(http.clone(), channel_id.clone(), data.clone())).await;
+            let page = match result {
+                Ok(page) => page,
+                Err(e) => return Some((Err(e), None)),
+            };
+            let messages = page.messages();
+            let next = match messages.last() {
+                Some(last) if messages.len() as i64 >= limit && advance(&mut data, last) => {
+                    Some((http, channel_id, data))
+                }
+                _ => None,
+            };
+            if messages.is_empty() {
+                return None;
+            }
+            Some((Ok(page), next))
+        }
+    })
+    .boxed()
+}
@@ -0,0 +1,5 @@
+pub mod session_edit;
+pub mod session_login;
+pub mod session_logout;
+pub mod session_revoke;
+pub mod sessions_fetch;
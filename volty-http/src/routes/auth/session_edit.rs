@@ -0,0 +1,28 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+use super::sessions_fetch::SessionInfo;
+
+/// # Edit Data
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct SessionEdit {
+    /// Session friendly name
+    friendly_name: String,
+}
+
+impl Http {
+    pub async fn rename_session(
+        &self,
+        session_id: impl std::fmt::Display,
+        friendly_name: impl std::fmt::Display,
+    ) -> Result<SessionInfo, HttpError> {
+        let data = SessionEdit {
+            friendly_name: friendly_name.to_string(),
+        };
+        let path = format!("auth/session/{session_id}");
+        let request = self.request(Method::PATCH, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
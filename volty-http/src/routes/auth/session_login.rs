@@ -0,0 +1,138 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// MFA method
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum MfaMethod {
+    Password,
+    Recovery,
+    Totp,
+}
+
+/// MFA response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MfaResponse {
+    Password { password: String },
+    Recovery { recovery_code: String },
+    Totp { totp_code: String },
+}
+
+impl MfaResponse {
+    pub fn password(password: impl std::fmt::Display) -> Self {
+        Self::Password {
+            password: password.to_string(),
+        }
+    }
+
+    pub fn recovery(recovery_code: impl std::fmt::Display) -> Self {
+        Self::Recovery {
+            recovery_code: recovery_code.to_string(),
+        }
+    }
+
+    pub fn totp(totp_code: impl std::fmt::Display) -> Self {
+        Self::Totp {
+            totp_code: totp_code.to_string(),
+        }
+    }
+}
+
+/// # Login Data
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Login {
+    Email {
+        /// Email
+        email: String,
+        /// Password
+        password: String,
+        /// Friendly name used for the session
+        #[serde(skip_serializing_if = "Option::is_none")]
+        friendly_name: Option<String>,
+    },
+    Mfa {
+        /// Unvalidated or authorised MFA ticket
+        ///
+        /// Used to resolve the correct account
+        mfa_ticket: String,
+        /// Valid MFA response
+        ///
+        /// This will take precedence over the `password` field where applicable
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mfa_response: Option<MfaResponse>,
+        /// Friendly name used for the session
+        #[serde(skip_serializing_if = "Option::is_none")]
+        friendly_name: Option<String>,
+    },
+}
+
+impl Login {
+    pub fn email(email: impl std::fmt::Display, password: impl std::fmt::Display) -> Self {
+        Self::Email {
+            email: email.to_string(),
+            password: password.to_string(),
+            friendly_name: None,
+        }
+    }
+
+    /// Answers the ticket from [LoginResponse::Mfa].
+    pub fn mfa(ticket: impl std::fmt::Display, response: impl Into<MfaResponse>) -> Self {
+        Self::Mfa {
+            mfa_ticket: ticket.to_string(),
+            mfa_response: Some(response.into()),
+            friendly_name: None,
+        }
+    }
+
+    pub fn friendly_name(mut self, name: impl std::fmt::Display) -> Self {
+        match &mut self {
+            Self::Email { friendly_name, .. } | Self::Mfa { friendly_name, .. } => {
+                *friendly_name = Some(name.to_string());
+            }
+        }
+        self
+    }
+}
+
+/// Session information
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Session {
+    /// Unique Id
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// User Id
+    pub user_id: String,
+    /// Session token
+    pub token: String,
+    /// Display name
+    pub name: String,
+}
+
+/// # Login Response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "result")]
+pub enum LoginResponse {
+    Success(Session),
+    #[serde(rename = "MFA")]
+    Mfa {
+        ticket: String,
+        allowed_methods: Vec<MfaMethod>,
+    },
+    Disabled {
+        user_id: String,
+    },
+}
+
+impl Http {
+    /// Creates a new session, see [Http::login] for a ready-to-use client.
+    pub async fn create_session(&self, data: impl Into<Login>) -> Result<LoginResponse, HttpError> {
+        let data: Login = data.into();
+        let request = self
+            .request(Method::POST, "auth/session/login")?
+            .json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,11 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Deletes the current session, invalidating this client's token.
+    pub async fn logout(&self) -> Result<(), HttpError> {
+        let request = self.request(Method::POST, "auth/session/logout")?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,31 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// # Query Parameters
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct SessionsRevoke {
+    /// Whether to also revoke the current session
+    revoke_self: bool,
+}
+
+impl Http {
+    pub async fn revoke_session(
+        &self,
+        session_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("auth/session/{session_id}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+
+    /// Revokes every other session, and the current one if `revoke_self` is set.
+    pub async fn revoke_all_sessions(&self, revoke_self: bool) -> Result<(), HttpError> {
+        let query = SessionsRevoke { revoke_self };
+        let request = self
+            .request(Method::DELETE, "auth/session/all")?
+            .query(&query);
+        self.send_request(request).await
+    }
+}
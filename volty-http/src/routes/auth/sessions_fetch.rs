@@ -0,0 +1,21 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// Session information without its token
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionInfo {
+    /// Unique Id
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Display name
+    pub name: String,
+}
+
+impl Http {
+    pub async fn fetch_sessions(&self) -> Result<Vec<SessionInfo>, HttpError> {
+        let request = self.request(Method::GET, "auth/session/all")?;
+        self.send_request(request).await
+    }
+}
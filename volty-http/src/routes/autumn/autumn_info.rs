@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+use super::upload_file::Tag;
+
+/// Upload settings for a single tag
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TagInfo {
+    /// Maximum file size (in bytes)
+    pub max_size: u64,
+    /// Whether uploads to this tag are allowed
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Only allow files of this type, e.g. `Image`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrict_content_type: Option<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// # Autumn Configuration
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutumnInfo {
+    /// Autumn version
+    #[serde(default)]
+    pub autumn: String,
+    /// Settings for each tag
+    #[serde(default)]
+    pub tags: HashMap<String, TagInfo>,
+}
+
+impl AutumnInfo {
+    pub fn tag(&self, tag: Tag) -> Option<&TagInfo> {
+        self.tags.get(&tag.to_string())
+    }
+}
+
+impl Http {
+    /// Fetches the file server's configuration, see [Http::autumn_info] for a cached copy.
+    pub async fn fetch_autumn_info(&self) -> Result<AutumnInfo, HttpError> {
+        let url = format!("{}/", self.autumn_url().await?);
+        let request = self.autumn_request(Method::GET, &url)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,191 @@
+use std::ops::{Bound, RangeBounds};
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use reqwest::{
+    header::{HeaderValue, RANGE},
+    Method, StatusCode,
+};
+use volty_types::media::attachment::File;
+
+use crate::{
+    error::{ApiError, HttpError},
+    Http,
+};
+
+/// A file being downloaded from autumn.
+pub struct Download {
+    content_type: String,
+    content_length: u64,
+    /// Leading bytes to drop if the server ignored the requested range
+    skip: u64,
+    /// Whether the server ignored the requested range and sends the whole file
+    ignored_range: bool,
+    chunks: BoxStream<'static, Result<Bytes, HttpError>>,
+}
+
+impl Download {
+    /// Content type recorded in the file's metadata.
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    /// Number of bytes this download yields.
+    pub fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    /// Reads the whole download into memory.
+    pub async fn bytes(self) -> Result<Bytes, HttpError> {
+        let mut buf = BytesMut::with_capacity(self.content_length as usize);
+        let mut stream = self.stream();
+        while let Some(chunk) = stream.try_next().await? {
+            buf.extend_from_slice(&chunk);
+        }
+        Ok(buf.freeze())
+    }
+
+    /// Yields the body in chunks as they arrive.
+    ///
+    /// If the server ignored the requested range, the bytes outside it are dropped.
+    /// Otherwise fails with [ApiError::PayloadTooLarge] if the server sends more than
+    /// [Download::content_length] bytes.
+    pub fn stream(self) -> BoxStream<'static, Result<Bytes, HttpError>> {
+        limit_chunks(
+            self.chunks,
+            self.skip,
+            self.content_length,
+            self.ignored_range,
+        )
+    }
+}
+
+/// Drops the first `skip` bytes and yields at most `remaining` bytes after them.
+///
+/// If `truncate` is set the surplus is cut off, otherwise it is an error.
+fn limit_chunks(
+    chunks: BoxStream<'static, Result<Bytes, HttpError>>,
+    skip: u64,
+    remaining: u64,
+    truncate: bool,
+) -> BoxStream<'static, Result<Bytes, HttpError>> {
+    let state = Some((chunks, skip, remaining));
+    stream::unfold(state, move |state| async move {
+        let (mut chunks, mut skip, mut remaining) = state?;
+        loop {
+            if truncate && remaining == 0 {
+                return None;
+            }
+            let mut chunk = match chunks.next().await {
+                Some(Ok(chunk)) => chunk,
+                Some(Err(e)) => return Some((Err(e), None)),
+                None => return None,
+            };
+            if skip > 0 {
+                let n = skip.min(chunk.len() as u64);
+                skip -= n;
+                let _ = chunk.split_to(n as usize);
+            }
+            if chunk.is_empty() {
+                continue;
+            }
+            let len = chunk.len() as u64;
+            if len > remaining {
+                if !truncate {
+                    return Some((Err(ApiError::PayloadTooLarge.into()), None));
+                }
+                chunk.truncate(remaining as usize);
+                return Some((Ok(chunk), None));
+            }
+            remaining -= len;
+            return Some((Ok(chunk), Some((chunks, skip, remaining))));
+        }
+    })
+    .boxed()
+}
+
+impl Http {
+    pub async fn download_file(&self, file: &File) -> Result<Download, HttpError> {
+        self.download_file_range(file, ..).await
+    }
+
+    /// Downloads part of a file, clamped to the size in its metadata.
+    pub async fn download_file_range(
+        &self,
+        file: &File,
+        range: impl RangeBounds<u64>,
+    ) -> Result<Download, HttpError> {
+        let size = file.size.max(0) as u64;
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => size,
+        }
+        .min(size);
+        let partial = start > 0 || end < size;
+        if partial && start >= end {
+            return Err(ApiError::InvalidOperation.into());
+        }
+
+        let url = file.autumn_url(self.autumn_url().await?);
+        let mut request = self.autumn_request(Method::GET, &url)?;
+        if partial {
+            let range = format!("bytes={start}-{}", end - 1);
+            request = request.header(RANGE, HeaderValue::from_str(&range).unwrap());
+        }
+        let response = self.send_request_raw(request).await?;
+        let ignored_range = partial && response.status() != StatusCode::PARTIAL_CONTENT;
+        Ok(Download {
+            content_type: file.content_type.clone(),
+            content_length: end - start,
+            skip: if ignored_range { start } else { 0 },
+            ignored_range,
+            chunks: response.bytes_stream().map_err(HttpError::from).boxed(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    fn chunks(data: &[u8], size: usize) -> BoxStream<'static, Result<Bytes, HttpError>> {
+        let chunks: Vec<_> = data
+            .chunks(size)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        stream::iter(chunks).boxed()
+    }
+
+    fn collect(stream: BoxStream<'static, Result<Bytes, HttpError>>) -> Result<Vec<u8>, HttpError> {
+        block_on(stream.map_ok(|b| b.to_vec()).try_concat())
+    }
+
+    #[test]
+    fn ignored_range_is_cut_to_size() {
+        let data: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        let head = collect(limit_chunks(chunks(&data, 64), 0, 100, true)).unwrap();
+        assert_eq!(head, &data[..100]);
+        let middle = collect(limit_chunks(chunks(&data, 64), 100, 100, true)).unwrap();
+        assert_eq!(middle, &data[100..200]);
+    }
+
+    #[test]
+    fn surplus_bytes_are_an_error() {
+        let data = vec![0; 150];
+        let result = collect(limit_chunks(chunks(&data, 64), 0, 100, false));
+        assert!(matches!(
+            result,
+            Err(HttpError::Api(ApiError::PayloadTooLarge))
+        ));
+        let exact = collect(limit_chunks(chunks(&data, 64), 0, 150, false)).unwrap();
+        assert_eq!(exact, data);
+    }
+}
@@ -1 +1,3 @@
+pub mod autumn_info;
+pub mod download_file;
 pub mod upload_file;
@@ -1,7 +1,26 @@
-use reqwest::multipart::{Form, Part};
+use std::{
+    io::Cursor,
+    path::PathBuf,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use futures::StreamExt;
+use reqwest::{
+    multipart::{Form, Part},
+    Body, Method,
+};
 use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio_util::io::ReaderStream;
 
-use crate::{bucket::BucketKey, error::HttpError, Http};
+use crate::{
+    error::{ApiError, HttpError},
+    Http,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub enum Tag {
@@ -27,21 +46,197 @@ impl std::fmt::Display for Tag {
     }
 }
 
-#[derive(Debug)]
+type Reader = Pin<Box<dyn AsyncRead + Send + Sync>>;
+
+/// Number of leading bytes used to sniff the content type
+const SNIFF_LEN: usize = 16;
+
+enum Source {
+    Bytes(Vec<u8>),
+    Reader(Reader),
+    Path(PathBuf),
+}
+
 pub struct UploadFile {
-    form: Form,
+    source: Source,
+    file_name: Option<String>,
+    content_type: Option<String>,
+}
+
+impl std::fmt::Debug for UploadFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadFile")
+            .field("file_name", &self.file_name)
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
 }
 
 impl UploadFile {
     pub fn new(bytes: Vec<u8>, file_name: Option<impl std::fmt::Display>) -> Self {
-        let mut part = Part::bytes(bytes);
-        if let Some(file_name) = file_name {
-            part = part.file_name(file_name.to_string());
+        Self {
+            source: Source::Bytes(bytes),
+            file_name: file_name.map(|n| n.to_string()),
+            content_type: None,
+        }
+    }
+
+    /// Streams the upload from a reader instead of holding it in memory.
+    pub fn from_reader(
+        reader: impl AsyncRead + Send + Sync + 'static,
+        file_name: Option<impl std::fmt::Display>,
+    ) -> Self {
+        Self {
+            source: Source::Reader(Box::pin(reader)),
+            file_name: file_name.map(|n| n.to_string()),
+            content_type: None,
         }
+    }
+
+    /// Streams the upload from a file, named after the last path component.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned());
         Self {
-            form: Form::new().part("file", part),
+            source: Source::Path(path),
+            file_name,
+            content_type: None,
+        }
+    }
+
+    pub fn file_name(mut self, file_name: impl std::fmt::Display) -> Self {
+        self.file_name = Some(file_name.to_string());
+        self
+    }
+
+    /// Overrides the content type, which is otherwise sniffed from the
+    /// first bytes of the file or guessed from its name.
+    /// Generic containers like zip or mp4 are only assumed if the name doesn't say.
+    pub fn content_type(mut self, content_type: impl std::fmt::Display) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Fails early if the file is known to be larger than `max_size`,
+    /// otherwise sets `exceeded` and aborts the body once it gets there.
+    async fn into_part(
+        self,
+        max_size: Option<u64>,
+        exceeded: Arc<AtomicBool>,
+    ) -> Result<Part, HttpError> {
+        let too_large = |len: u64| max_size.is_some_and(|max| len > max);
+        let (part, head) = match self.source {
+            Source::Bytes(bytes) => {
+                if too_large(bytes.len() as u64) {
+                    return Err(ApiError::PayloadTooLarge.into());
+                }
+                let head = bytes[..bytes.len().min(SNIFF_LEN)].to_vec();
+                (Part::bytes(bytes), head)
+            }
+            Source::Reader(reader) => stream_part(reader, None, max_size, exceeded).await?,
+            Source::Path(path) => {
+                let file = tokio::fs::File::open(&path).await?;
+                let len = file.metadata().await?.len();
+                if too_large(len) {
+                    return Err(ApiError::PayloadTooLarge.into());
+                }
+                stream_part(Box::pin(file), Some(len), max_size, exceeded).await?
+            }
+        };
+
+        let content_type = self
+            .content_type
+            .or_else(|| guess_content_type(&head, self.file_name.as_deref()).map(str::to_string));
+        let mut part = match content_type {
+            Some(content_type) => part.mime_str(&content_type)?,
+            None => part,
+        };
+        if let Some(file_name) = self.file_name {
+            part = part.file_name(file_name);
+        }
+        Ok(part)
+    }
+}
+
+async fn stream_part(
+    mut reader: Reader,
+    len: Option<u64>,
+    max_size: Option<u64>,
+    exceeded: Arc<AtomicBool>,
+) -> Result<(Part, Vec<u8>), HttpError> {
+    let mut head = vec![0; SNIFF_LEN];
+    let mut filled = 0;
+    while filled < SNIFF_LEN {
+        let n = reader.read(&mut head[filled..]).await?;
+        if n == 0 {
+            break;
         }
+        filled += n;
     }
+    head.truncate(filled);
+
+    let mut total = 0;
+    let stream = ReaderStream::new(Cursor::new(head.clone()).chain(reader)).map(move |chunk| {
+        let chunk = chunk?;
+        total += chunk.len() as u64;
+        if max_size.is_some_and(|max| total > max) {
+            exceeded.store(true, Ordering::Relaxed);
+            return Err(std::io::Error::other(
+                "file exceeds the size limit of its tag",
+            ));
+        }
+        Ok(chunk)
+    });
+    let body = Body::wrap_stream(stream);
+    let part = match len {
+        Some(len) => Part::stream_with_length(body, len),
+        None => Part::stream(body),
+    };
+    Ok((part, head))
+}
+
+/// Prefers exact signatures, then the file name, then container signatures.
+fn guess_content_type(head: &[u8], file_name: Option<&str>) -> Option<&'static str> {
+    sniff(head)
+        .or_else(|| mime_guess::from_path(file_name?).first_raw())
+        .or_else(|| sniff_container(head))
+}
+
+/// Guesses the content type from signatures that identify a single format.
+fn sniff(head: &[u8]) -> Option<&'static str> {
+    let content_type = match head {
+        [0x89, b'P', b'N', b'G', ..] => "image/png",
+        [0xFF, 0xD8, 0xFF, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', ..] => "image/gif",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => "image/webp",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => "audio/wav",
+        [_, _, _, _, b'f', b't', b'y', b'p', brand @ ..] => match brand.get(..4) {
+            Some(b"qt  ") => "video/quicktime",
+            Some(b"avif" | b"avis") => "image/avif",
+            Some(b"heic" | b"heix") => "image/heic",
+            Some(b"M4A ") => "audio/mp4",
+            _ => return None,
+        },
+        [b'f', b'L', b'a', b'C', ..] => "audio/flac",
+        [b'I', b'D', b'3', ..] => "audio/mpeg",
+        [b'%', b'P', b'D', b'F', b'-', ..] => "application/pdf",
+        _ => return None,
+    };
+    Some(content_type)
+}
+
+/// Guesses the content type from container signatures,
+/// only used when the file name doesn't tell what's inside.
+fn sniff_container(head: &[u8]) -> Option<&'static str> {
+    let content_type = match head {
+        [_, _, _, _, b'f', b't', b'y', b'p', b'm', b'i', b'f', b'1', ..] => "image/heif",
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => "video/mp4",
+        [0x1A, 0x45, 0xDF, 0xA3, ..] => "video/webm",
+        [b'O', b'g', b'g', b'S', ..] => "audio/ogg",
+        [b'P', b'K', 0x03, 0x04, ..] => "application/zip",
+        _ => return None,
+    };
+    Some(content_type)
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -50,15 +245,75 @@ pub struct UploadResponse {
 }
 
 impl Http {
+    /// Uploads a file to autumn, checking it against the tag's size limit first.
+    ///
+    /// Oversized files fail with [ApiError::PayloadTooLarge] without being sent in full.
+    /// If the limits can't be fetched the file is uploaded without a local check.
     pub async fn upload_file(
         &self,
         tag: Tag,
         file: UploadFile,
     ) -> Result<UploadResponse, HttpError> {
-        let url = format!("https://cdn.revoltusercontent.com/{tag}");
-        let request = self.client.post(url).multipart(file.form);
-        let response = request.send().await;
-        self.handle_response(response, BucketKey::Channels("autumn".to_string()))
-            .await
+        let max_size = match self.autumn_info().await {
+            Ok(info) => match info.tag(tag) {
+                Some(info) if !info.enabled => return Err(ApiError::InvalidOperation.into()),
+                Some(info) => Some(info.max_size),
+                None => None,
+            },
+            Err(e) => {
+                log::warn!("Uploading without size limit, autumn info failed: {:?}", e);
+                None
+            }
+        };
+        let url = format!("{}/{tag}", self.autumn_url().await?);
+        let exceeded = Arc::new(AtomicBool::new(false));
+        let part = file.into_part(max_size, exceeded.clone()).await?;
+        let request = self
+            .autumn_request(Method::POST, &url)?
+            .multipart(Form::new().part("file", part));
+        let result = self.send_request(request).await;
+        if exceeded.load(Ordering::Relaxed) {
+            return Err(ApiError::PayloadTooLarge.into());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ftyp(brand: &[u8; 4]) -> Vec<u8> {
+        [&[0, 0, 0, 0x20], &b"ftyp"[..], brand, &[0; 4]].concat()
+    }
+
+    #[test]
+    fn ftyp_brands_are_told_apart() {
+        assert_eq!(guess_content_type(&ftyp(b"avif"), None), Some("image/avif"));
+        assert_eq!(guess_content_type(&ftyp(b"heic"), None), Some("image/heic"));
+        assert_eq!(guess_content_type(&ftyp(b"M4A "), None), Some("audio/mp4"));
+        assert_eq!(
+            guess_content_type(&ftyp(b"qt  "), None),
+            Some("video/quicktime")
+        );
+        assert_eq!(guess_content_type(&ftyp(b"isom"), None), Some("video/mp4"));
+    }
+
+    #[test]
+    fn file_name_beats_containers() {
+        let zip = b"PK\x03\x04\x14\x00\x06\x00";
+        assert_eq!(
+            guess_content_type(zip, Some("report.docx")),
+            mime_guess::from_path("report.docx").first_raw()
+        );
+        assert_eq!(guess_content_type(zip, None), Some("application/zip"));
+        assert_eq!(
+            guess_content_type(&ftyp(b"mp42"), Some("song.m4a")),
+            mime_guess::from_path("song.m4a").first_raw()
+        );
+        assert_eq!(
+            guess_content_type(b"\x89PNG\r\n\x1a\n", Some("image.jpg")),
+            Some("image/png")
+        );
     }
 }
@@ -0,0 +1,59 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::{
+    users::{bot::Bot, user::User},
+    util::regex::RE_USERNAME,
+};
+
+use crate::{error::HttpError, Http};
+
+/// # Bot Details
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct BotCreate {
+    /// Bot username
+    #[validate(length(min = 2, max = 32), regex(path = *RE_USERNAME))]
+    name: String,
+}
+
+impl BotCreate {
+    pub fn new(name: impl std::fmt::Display) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl From<String> for BotCreate {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for BotCreate {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+/// # Bot Response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BotWithUserResponse {
+    /// Bot object
+    #[serde(flatten)]
+    pub bot: Bot,
+    /// User object of the bot
+    pub user: User,
+}
+
+impl Http {
+    pub async fn create_bot(
+        &self,
+        data: impl Into<BotCreate>,
+    ) -> Result<BotWithUserResponse, HttpError> {
+        let data: BotCreate = data.into();
+        data.validate()?;
+        let request = self.request(Method::POST, "bots/create")?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,11 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn delete_bot(&self, bot_id: impl std::fmt::Display) -> Result<(), HttpError> {
+        let path = format!("bots/{bot_id}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
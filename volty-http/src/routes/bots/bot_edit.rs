@@ -0,0 +1,92 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::{users::bot::FieldsBot, util::regex::RE_USERNAME};
+
+use crate::{error::HttpError, Http};
+
+use super::bot_create::BotWithUserResponse;
+
+/// # Bot Details
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct BotEdit {
+    /// Bot username
+    #[validate(length(min = 2, max = 32), regex(path = *RE_USERNAME))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// Whether the bot can be added by anyone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    public: Option<bool>,
+
+    /// Whether analytics should be gathered for this bot
+    ///
+    /// Must be enabled in order to show up on [Revolt Discover](https://rvlt.gg).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analytics: Option<bool>,
+
+    /// Interactions URL
+    #[validate(length(min = 1, max = 2048))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interactions_url: Option<String>,
+
+    /// Fields to remove from bot object
+    #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove: Option<Vec<FieldsBot>>,
+}
+
+impl BotEdit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl std::fmt::Display) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    pub fn analytics(mut self, analytics: bool) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
+    pub fn interactions_url(mut self, interactions_url: impl std::fmt::Display) -> Self {
+        self.interactions_url = Some(interactions_url.to_string());
+        self
+    }
+
+    /// Generates a new token, invalidating the old one.
+    pub fn reset_token(self) -> Self {
+        self.remove(FieldsBot::Token)
+    }
+
+    pub fn remove(mut self, remove: impl Into<FieldsBot>) -> Self {
+        let remove = remove.into();
+        if let Some(r) = &mut self.remove {
+            r.push(remove);
+        } else {
+            self.remove = Some(vec![remove])
+        }
+        self
+    }
+}
+
+impl Http {
+    pub async fn edit_bot(
+        &self,
+        bot_id: impl std::fmt::Display,
+        data: impl Into<BotEdit>,
+    ) -> Result<BotWithUserResponse, HttpError> {
+        let data: BotEdit = data.into();
+        data.validate()?;
+        let path = format!("bots/{bot_id}");
+        let request = self.request(Method::PATCH, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,26 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::users::{bot::Bot, user::User};
+
+use crate::{error::HttpError, Http};
+
+/// # Bot Response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FetchBotResponse {
+    /// Bot object
+    pub bot: Bot,
+    /// User object
+    pub user: User,
+}
+
+impl Http {
+    /// Fetches a bot we own.
+    pub async fn fetch_bot(
+        &self,
+        bot_id: impl std::fmt::Display,
+    ) -> Result<FetchBotResponse, HttpError> {
+        let path = format!("bots/{bot_id}");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,32 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::media::attachment::File;
+
+use crate::{error::HttpError, Http};
+
+/// # Public Bot
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PublicBot {
+    /// Bot Id
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Bot Username
+    pub username: String,
+    /// Profile Avatar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<File>,
+    /// Profile Description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Http {
+    pub async fn fetch_public_bot(
+        &self,
+        bot_id: impl std::fmt::Display,
+    ) -> Result<PublicBot, HttpError> {
+        let path = format!("bots/{bot_id}/invite");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
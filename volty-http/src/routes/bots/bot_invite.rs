@@ -0,0 +1,47 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// # Invite Destination
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BotInvite {
+    /// Invite to a server
+    Server {
+        /// Server Id
+        server: String,
+    },
+    /// Invite to a group
+    Group {
+        /// Group Id
+        group: String,
+    },
+}
+
+impl BotInvite {
+    pub fn server(server_id: impl std::fmt::Display) -> Self {
+        Self::Server {
+            server: server_id.to_string(),
+        }
+    }
+
+    pub fn group(group_id: impl std::fmt::Display) -> Self {
+        Self::Group {
+            group: group_id.to_string(),
+        }
+    }
+}
+
+impl Http {
+    pub async fn invite_bot(
+        &self,
+        bot_id: impl std::fmt::Display,
+        destination: impl Into<BotInvite>,
+    ) -> Result<(), HttpError> {
+        let data: BotInvite = destination.into();
+        let path = format!("bots/{bot_id}/invite");
+        let request = self.request(Method::POST, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
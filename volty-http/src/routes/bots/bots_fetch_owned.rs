@@ -0,0 +1,21 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::users::{bot::Bot, user::User};
+
+use crate::{error::HttpError, Http};
+
+/// # Owned Bots Response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OwnedBotsResponse {
+    /// Bot objects
+    pub bots: Vec<Bot>,
+    /// User objects
+    pub users: Vec<User>,
+}
+
+impl Http {
+    pub async fn fetch_owned_bots(&self) -> Result<OwnedBotsResponse, HttpError> {
+        let request = self.request(Method::GET, "bots/@me")?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,7 @@
+pub mod bot_create;
+pub mod bot_delete;
+pub mod bot_edit;
+pub mod bot_fetch;
+pub mod bot_fetch_public;
+pub mod bot_invite;
+pub mod bots_fetch_owned;
@@ -0,0 +1,16 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Marks a channel as read up to the given message.
+    pub async fn ack_message(
+        &self,
+        channel_id: impl std::fmt::Display,
+        message_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("channels/{channel_id}/ack/{message_id}");
+        let request = self.request(Method::PUT, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,15 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Deletes a server channel, leaves a group or closes a DM.
+    pub async fn delete_channel(
+        &self,
+        channel_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("channels/{channel_id}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,114 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::channels::channel::{Channel, FieldsChannel};
+
+use crate::{error::HttpError, Http};
+
+/// # Channel Details
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct ChannelEdit {
+    /// Channel name
+    #[validate(length(min = 1, max = 32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// Channel description
+    #[validate(length(min = 0, max = 1024))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// Group owner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+
+    /// Icon
+    ///
+    /// Provide an Autumn attachment Id.
+    #[validate(length(min = 1, max = 128))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+
+    /// Whether this channel is age-restricted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+
+    /// Whether this channel is archived
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+
+    /// Fields to remove from channel
+    #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove: Option<Vec<FieldsChannel>>,
+}
+
+impl ChannelEdit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl std::fmt::Display) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl std::fmt::Display) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn owner(mut self, owner: impl std::fmt::Display) -> Self {
+        self.owner = Some(owner.to_string());
+        self
+    }
+
+    pub fn icon(mut self, icon: impl std::fmt::Display) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+
+    pub fn nsfw(mut self) -> Self {
+        self.nsfw = Some(true);
+        self
+    }
+
+    pub fn unnsfw(mut self) -> Self {
+        self.nsfw = Some(false);
+        self
+    }
+
+    pub fn archive(mut self) -> Self {
+        self.archived = Some(true);
+        self
+    }
+
+    pub fn unarchive(mut self) -> Self {
+        self.archived = Some(false);
+        self
+    }
+
+    pub fn remove(mut self, remove: impl Into<FieldsChannel>) -> Self {
+        let remove = remove.into();
+        if let Some(r) = &mut self.remove {
+            r.push(remove);
+        } else {
+            self.remove = Some(vec![remove])
+        }
+        self
+    }
+}
+
+impl Http {
+    pub async fn edit_channel(
+        &self,
+        channel_id: impl std::fmt::Display,
+        data: impl Into<ChannelEdit>,
+    ) -> Result<Channel, HttpError> {
+        let data: ChannelEdit = data.into();
+        data.validate()?;
+        let path = format!("channels/{channel_id}");
+        let request = self.request(Method::PATCH, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,15 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn add_group_member(
+        &self,
+        group_id: impl std::fmt::Display,
+        user_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("channels/{group_id}/recipients/{user_id}");
+        let request = self.request(Method::PUT, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,92 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::channels::channel::Channel;
+
+use crate::{error::HttpError, Http};
+
+/// # Group Data
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct GroupCreate {
+    /// Group name
+    #[validate(length(min = 1, max = 32))]
+    name: String,
+
+    /// Group description
+    #[validate(length(min = 0, max = 1024))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// Group icon
+    #[validate(length(min = 1, max = 128))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+
+    /// Array of user IDs to add to the group
+    ///
+    /// Must be friends with these users.
+    #[validate(length(min = 0, max = 49))]
+    users: Vec<String>,
+
+    /// Whether this group is age-restricted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+}
+
+impl GroupCreate {
+    pub fn new(name: impl std::fmt::Display) -> Self {
+        Self {
+            name: name.to_string(),
+            description: None,
+            icon: None,
+            users: Vec::new(),
+            nsfw: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl std::fmt::Display) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn icon(mut self, icon: impl std::fmt::Display) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+
+    pub fn user(mut self, user_id: impl std::fmt::Display) -> Self {
+        self.users.push(user_id.to_string());
+        self
+    }
+
+    pub fn users<S: std::fmt::Display>(mut self, users: impl IntoIterator<Item = S>) -> Self {
+        self.users = users.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn nsfw(mut self) -> Self {
+        self.nsfw = Some(true);
+        self
+    }
+}
+
+impl From<String> for GroupCreate {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for GroupCreate {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Http {
+    pub async fn create_group(&self, data: impl Into<GroupCreate>) -> Result<Channel, HttpError> {
+        let data: GroupCreate = data.into();
+        data.validate()?;
+        let request = self.request(Method::POST, "channels/create")?.json(&data);
+        self.send_request(request).await
+    }
+}
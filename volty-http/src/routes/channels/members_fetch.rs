@@ -0,0 +1,15 @@
+use reqwest::Method;
+use volty_types::users::user::User;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn fetch_group_members(
+        &self,
+        group_id: impl std::fmt::Display,
+    ) -> Result<Vec<User>, HttpError> {
+        let path = format!("channels/{group_id}/members");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
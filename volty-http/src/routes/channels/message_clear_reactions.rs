@@ -0,0 +1,16 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Remove all reactions from a message.
+    pub async fn clear_reactions(
+        &self,
+        channel_id: impl std::fmt::Display,
+        message_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("channels/{channel_id}/messages/{message_id}/reactions");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
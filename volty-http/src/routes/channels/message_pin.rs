@@ -0,0 +1,44 @@
+use futures::TryStreamExt;
+use reqwest::Method;
+use volty_types::channels::message::{Message, MessageSort};
+
+use crate::{error::HttpError, Http};
+
+use super::message_search::MessageSearch;
+
+impl Http {
+    pub async fn pin_message(
+        &self,
+        channel_id: impl std::fmt::Display,
+        message_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("channels/{channel_id}/messages/{message_id}/pin");
+        let request = self.request(Method::POST, &path)?;
+        self.send_request(request).await
+    }
+
+    pub async fn unpin_message(
+        &self,
+        channel_id: impl std::fmt::Display,
+        message_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("channels/{channel_id}/messages/{message_id}/pin");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+
+    /// Lists all pinned messages in a channel, newest first.
+    pub async fn fetch_pinned_messages(
+        &self,
+        channel_id: impl std::fmt::Display,
+    ) -> Result<Vec<Message>, HttpError> {
+        let data = MessageSearch::default()
+            .pinned()
+            .sort(MessageSort::Latest)
+            .limit(100);
+        self.search_messages_stream(channel_id, data)
+            .map_ok(|page| page.messages().to_vec())
+            .try_concat()
+            .await
+    }
+}
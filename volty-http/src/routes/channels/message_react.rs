@@ -0,0 +1,18 @@
+use reqwest::Method;
+
+use crate::{encode_segment, error::HttpError, Http};
+
+impl Http {
+    /// React to a message with a custom emoji id or a unicode emoji.
+    pub async fn react_message(
+        &self,
+        channel_id: impl std::fmt::Display,
+        message_id: impl std::fmt::Display,
+        emoji: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let emoji = encode_segment(emoji);
+        let path = format!("channels/{channel_id}/messages/{message_id}/reactions/{emoji}");
+        let request = self.request(Method::PUT, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,150 @@
+use futures::stream::BoxStream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::channels::message::{BulkMessageResponse, MessageSort};
+
+use crate::{
+    error::HttpError,
+    pager::{message_pages, DEFAULT_LIMIT},
+    Http,
+};
+
+/// # Search Parameters
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct MessageSearch {
+    /// Full-text search query
+    ///
+    /// See [MongoDB documentation](https://docs.mongodb.com/manual/text-search/#-text-operator) for more information.
+    #[validate(length(min = 1, max = 64))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+
+    /// Whether to only search for pinned messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pinned: Option<bool>,
+
+    /// Maximum number of messages to fetch
+    #[validate(range(min = 1, max = 100))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+
+    /// Message id before which messages should be fetched
+    #[validate(length(min = 26, max = 26))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<String>,
+
+    /// Message id after which messages should be fetched
+    #[validate(length(min = 26, max = 26))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+
+    /// Message sort direction
+    ///
+    /// By default, it will be sorted by relevance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<MessageSort>,
+
+    /// Whether to include user (and member, if server channel) objects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_users: Option<bool>,
+}
+
+impl MessageSearch {
+    pub fn new(query: impl std::fmt::Display) -> Self {
+        Self {
+            query: Some(query.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn pinned(mut self) -> Self {
+        self.pinned = Some(true);
+        self
+    }
+
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.limit = Some(limit.into());
+        self
+    }
+
+    pub fn before(mut self, message_id: impl std::fmt::Display) -> Self {
+        self.before = Some(message_id.to_string());
+        self
+    }
+
+    pub fn after(mut self, message_id: impl std::fmt::Display) -> Self {
+        self.after = Some(message_id.to_string());
+        self
+    }
+
+    pub fn sort(mut self, sort: impl Into<MessageSort>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    pub fn include_users(mut self) -> Self {
+        self.include_users = Some(true);
+        self
+    }
+}
+
+impl From<String> for MessageSearch {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for MessageSearch {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Http {
+    pub async fn search_messages(
+        &self,
+        channel_id: impl std::fmt::Display,
+        data: impl Into<MessageSearch>,
+    ) -> Result<BulkMessageResponse, HttpError> {
+        let data: MessageSearch = data.into();
+        data.validate()?;
+        let path = format!("channels/{channel_id}/search");
+        let request = self.request(Method::POST, &path)?.json(&data);
+        self.send_request(request).await
+    }
+
+    /// Walks all search results one page at a time.
+    ///
+    /// Results sorted by [MessageSort::Latest] are paged backwards from `before`
+    /// and [MessageSort::Oldest] forwards from `after`. Results sorted by
+    /// [MessageSort::Relevance] cannot be paged, so only the first page is yielded.
+    /// Rate limits are waited out instead of returned.
+    pub fn search_messages_stream(
+        &self,
+        channel_id: impl std::fmt::Display,
+        data: impl Into<MessageSearch>,
+    ) -> BoxStream<'static, Result<BulkMessageResponse, HttpError>> {
+        let data: MessageSearch = data.into();
+        let sort = data.sort.unwrap_or_default();
+        let limit = data.limit.unwrap_or(DEFAULT_LIMIT);
+        message_pages(
+            self,
+            channel_id,
+            data,
+            limit,
+            |http, channel_id, data| async move { http.search_messages(&channel_id, data).await },
+            move |data, last| match sort {
+                MessageSort::Latest => {
+                    data.before = Some(last.id.clone());
+                    true
+                }
+                MessageSort::Oldest => {
+                    data.after = Some(last.id.clone());
+                    true
+                }
+                MessageSort::Relevance => false,
+            },
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{encode_segment, error::HttpError, Http};
+
+/// # Query Parameters
+///
+/// With no options set, only our own reaction is removed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReactionRemove {
+    /// Remove a specific user's reaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_id: Option<String>,
+
+    /// Remove every user's reaction for this emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_all: Option<bool>,
+}
+
+impl ReactionRemove {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn user(mut self, user_id: impl std::fmt::Display) -> Self {
+        self.user_id = Some(user_id.to_string());
+        self
+    }
+
+    /// Removes every user's reaction for this emoji,
+    /// use [Http::clear_reactions] to remove all emojis.
+    pub fn all(mut self) -> Self {
+        self.remove_all = Some(true);
+        self
+    }
+}
+
+impl Http {
+    /// Remove reactions of a custom emoji id or a unicode emoji from a message.
+    pub async fn unreact_message(
+        &self,
+        channel_id: impl std::fmt::Display,
+        message_id: impl std::fmt::Display,
+        emoji: impl std::fmt::Display,
+        data: impl Into<ReactionRemove>,
+    ) -> Result<(), HttpError> {
+        let data: ReactionRemove = data.into();
+        let emoji = encode_segment(emoji);
+        let path = format!("channels/{channel_id}/messages/{message_id}/reactions/{emoji}");
+        let request = self.request(Method::DELETE, &path)?.query(&data);
+        self.send_request(request).await
+    }
+}
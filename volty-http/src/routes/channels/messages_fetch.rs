@@ -0,0 +1,130 @@
+use futures::stream::BoxStream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::channels::message::{BulkMessageResponse, MessageSort};
+
+use crate::{
+    error::HttpError,
+    pager::{message_pages, DEFAULT_LIMIT},
+    Http,
+};
+
+/// # Query Parameters
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct MessagesFetch {
+    /// Maximum number of messages to fetch
+    ///
+    /// For fetching nearby messages, this is `(limit + 1)`.
+    #[validate(range(min = 1, max = 100))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+
+    /// Message id before which messages should be fetched
+    #[validate(length(min = 26, max = 26))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<String>,
+
+    /// Message id after which messages should be fetched
+    #[validate(length(min = 26, max = 26))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+
+    /// Message sort direction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<MessageSort>,
+
+    /// Message id to search around
+    ///
+    /// Specifying 'nearby' ignores 'before', 'after' and 'sort'.
+    /// It will also take half of limit rounded as the limits to each side.
+    /// It also fetches the message ID specified.
+    #[validate(length(min = 26, max = 26))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nearby: Option<String>,
+
+    /// Whether to include user (and member, if server channel) objects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_users: Option<bool>,
+}
+
+impl MessagesFetch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.limit = Some(limit.into());
+        self
+    }
+
+    pub fn before(mut self, message_id: impl std::fmt::Display) -> Self {
+        self.before = Some(message_id.to_string());
+        self
+    }
+
+    pub fn after(mut self, message_id: impl std::fmt::Display) -> Self {
+        self.after = Some(message_id.to_string());
+        self
+    }
+
+    pub fn sort(mut self, sort: impl Into<MessageSort>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    pub fn nearby(mut self, message_id: impl std::fmt::Display) -> Self {
+        self.nearby = Some(message_id.to_string());
+        self
+    }
+
+    pub fn include_users(mut self) -> Self {
+        self.include_users = Some(true);
+        self
+    }
+}
+
+impl Http {
+    pub async fn fetch_messages(
+        &self,
+        channel_id: impl std::fmt::Display,
+        data: impl Into<MessagesFetch>,
+    ) -> Result<BulkMessageResponse, HttpError> {
+        let data: MessagesFetch = data.into();
+        data.validate()?;
+        let path = format!("channels/{channel_id}/messages");
+        let request = self.request(Method::GET, &path)?.query(&data);
+        self.send_request(request).await
+    }
+
+    /// Walks a channel's history one page at a time.
+    ///
+    /// Pages are fetched backwards from `before` unless the sort is
+    /// [MessageSort::Oldest], in which case they are fetched forwards from `after`.
+    /// `nearby` is ignored. Rate limits are waited out instead of returned.
+    pub fn messages_stream(
+        &self,
+        channel_id: impl std::fmt::Display,
+        data: impl Into<MessagesFetch>,
+    ) -> BoxStream<'static, Result<BulkMessageResponse, HttpError>> {
+        let mut data: MessagesFetch = data.into();
+        data.nearby = None;
+        let forwards = matches!(data.sort, Some(MessageSort::Oldest));
+        let limit = data.limit.unwrap_or(DEFAULT_LIMIT);
+        message_pages(
+            self,
+            channel_id,
+            data,
+            limit,
+            |http, channel_id, data| async move { http.fetch_messages(&channel_id, data).await },
+            move |data, last| {
+                if forwards {
+                    data.after = Some(last.id.clone());
+                } else {
+                    data.before = Some(last.id.clone());
+                }
+                true
+            },
+        )
+    }
+}
@@ -1,5 +1,21 @@
+pub mod channel_ack;
+pub mod channel_delete;
+pub mod channel_edit;
+pub mod group_add_member;
+pub mod group_create;
+pub mod group_remove_member;
 pub mod invite_create;
+pub mod members_fetch;
+pub mod message_clear_reactions;
 pub mod message_delete;
 pub mod message_edit;
 pub mod message_fetch;
+pub mod message_pin;
+pub mod message_react;
+pub mod message_search;
 pub mod message_send;
+pub mod message_unreact;
+pub mod messages_fetch;
+pub mod permissions_set;
+pub mod webhook_create;
+pub mod webhooks_fetch;
@@ -0,0 +1,61 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::{channels::channel::Channel, permissions::Override};
+
+use crate::{error::HttpError, Http};
+
+/// # Permission Value
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct PermissionsSet {
+    /// Allow / deny values to set for this role
+    permissions: Override,
+}
+
+/// # Permission Value
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct GroupPermissionsSet {
+    /// Permission values to set for members in a group
+    permissions: u64,
+}
+
+impl Http {
+    pub async fn set_channel_role_permissions(
+        &self,
+        channel_id: impl std::fmt::Display,
+        role_id: impl std::fmt::Display,
+        permissions: impl Into<Override>,
+    ) -> Result<Channel, HttpError> {
+        let data = PermissionsSet {
+            permissions: permissions.into(),
+        };
+        let path = format!("channels/{channel_id}/permissions/{role_id}");
+        let request = self.request(Method::PUT, &path)?.json(&data);
+        self.send_request(request).await
+    }
+
+    pub async fn set_channel_default_permissions(
+        &self,
+        channel_id: impl std::fmt::Display,
+        permissions: impl Into<Override>,
+    ) -> Result<Channel, HttpError> {
+        let data = PermissionsSet {
+            permissions: permissions.into(),
+        };
+        let path = format!("channels/{channel_id}/permissions/default");
+        let request = self.request(Method::PUT, &path)?.json(&data);
+        self.send_request(request).await
+    }
+
+    pub async fn set_group_permissions(
+        &self,
+        group_id: impl std::fmt::Display,
+        permissions: impl Into<u64>,
+    ) -> Result<Channel, HttpError> {
+        let data = GroupPermissionsSet {
+            permissions: permissions.into(),
+        };
+        let path = format!("channels/{group_id}/permissions/default");
+        let request = self.request(Method::PUT, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,19 @@
+use reqwest::Method;
+use validator::Validate;
+use volty_types::channels::webhook::{CreateWebhookBody, Webhook};
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn create_webhook(
+        &self,
+        channel_id: impl std::fmt::Display,
+        data: impl Into<CreateWebhookBody>,
+    ) -> Result<Webhook, HttpError> {
+        let data: CreateWebhookBody = data.into();
+        data.validate()?;
+        let path = format!("channels/{channel_id}/webhooks");
+        let request = self.request(Method::POST, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
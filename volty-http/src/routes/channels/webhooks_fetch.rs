@@ -0,0 +1,15 @@
+use reqwest::Method;
+use volty_types::channels::webhook::Webhook;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn fetch_webhooks(
+        &self,
+        channel_id: impl std::fmt::Display,
+    ) -> Result<Vec<Webhook>, HttpError> {
+        let path = format!("channels/{channel_id}/webhooks");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,72 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::{
+    media::emoji::{Emoji, EmojiParent},
+    util::regex::RE_EMOJI,
+};
+
+use crate::{
+    error::HttpError,
+    routes::autumn::upload_file::{Tag, UploadFile},
+    Http,
+};
+
+/// # Emoji Data
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct EmojiCreate {
+    /// Server emoji name
+    #[validate(length(min = 1, max = 32), regex(path = *RE_EMOJI))]
+    name: String,
+
+    /// Parent information
+    parent: EmojiParent,
+
+    /// Whether the emoji is mature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+}
+
+impl EmojiCreate {
+    pub fn new(name: impl std::fmt::Display, server_id: impl std::fmt::Display) -> Self {
+        Self {
+            name: name.to_string(),
+            parent: EmojiParent::Server {
+                id: server_id.to_string(),
+            },
+            nsfw: None,
+        }
+    }
+
+    pub fn nsfw(mut self) -> Self {
+        self.nsfw = Some(true);
+        self
+    }
+}
+
+impl Http {
+    /// Creates an emoji from a file uploaded with [Tag::Emojis].
+    pub async fn create_emoji(
+        &self,
+        file_id: impl std::fmt::Display,
+        data: impl Into<EmojiCreate>,
+    ) -> Result<Emoji, HttpError> {
+        let data: EmojiCreate = data.into();
+        data.validate()?;
+        let path = format!("custom/emoji/{file_id}");
+        let request = self.request(Method::PUT, &path)?.json(&data);
+        self.send_request(request).await
+    }
+
+    /// Uploads an image and creates an emoji from it.
+    pub async fn upload_emoji(
+        &self,
+        file: UploadFile,
+        data: impl Into<EmojiCreate>,
+    ) -> Result<Emoji, HttpError> {
+        let data: EmojiCreate = data.into();
+        data.validate()?;
+        let upload = self.upload_file(Tag::Emojis, file).await?;
+        self.create_emoji(upload.id, data).await
+    }
+}
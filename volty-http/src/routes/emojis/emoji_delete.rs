@@ -0,0 +1,11 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn delete_emoji(&self, emoji_id: impl std::fmt::Display) -> Result<(), HttpError> {
+        let path = format!("custom/emoji/{emoji_id}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,12 @@
+use reqwest::Method;
+use volty_types::media::emoji::Emoji;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn fetch_emoji(&self, emoji_id: impl std::fmt::Display) -> Result<Emoji, HttpError> {
+        let path = format!("custom/emoji/{emoji_id}");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
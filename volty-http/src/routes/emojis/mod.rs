@@ -0,0 +1,3 @@
+pub mod emoji_create;
+pub mod emoji_delete;
+pub mod emoji_fetch;
@@ -0,0 +1,72 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::media::attachment::File;
+
+use crate::{error::HttpError, Http};
+
+/// Public information about an invite
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum InviteResponse {
+    /// Server channel invite
+    Server {
+        /// Invite code
+        code: String,
+        /// Id of the server
+        server_id: String,
+        /// Name of the server
+        server_name: String,
+        /// Attachment for server icon
+        #[serde(skip_serializing_if = "Option::is_none")]
+        server_icon: Option<File>,
+        /// Attachment for server banner
+        #[serde(skip_serializing_if = "Option::is_none")]
+        server_banner: Option<File>,
+        /// Enum of server flags
+        #[serde(skip_serializing_if = "Option::is_none")]
+        server_flags: Option<i32>,
+        /// Id of server channel
+        channel_id: String,
+        /// Name of server channel
+        channel_name: String,
+        /// Description of server channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        channel_description: Option<String>,
+        /// Name of user who created the invite
+        user_name: String,
+        /// Avatar of the user who created the invite
+        #[serde(skip_serializing_if = "Option::is_none")]
+        user_avatar: Option<File>,
+        /// Number of members in this server
+        member_count: i64,
+    },
+    /// Group channel invite
+    Group {
+        /// Invite code
+        code: String,
+        /// Id of group channel
+        channel_id: String,
+        /// Name of group channel
+        channel_name: String,
+        /// Description of group channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        channel_description: Option<String>,
+        /// Name of user who created the invite
+        user_name: String,
+        /// Avatar of the user who created the invite
+        #[serde(skip_serializing_if = "Option::is_none")]
+        user_avatar: Option<File>,
+    },
+}
+
+impl Http {
+    pub async fn fetch_invite(
+        &self,
+        code: impl std::fmt::Display,
+    ) -> Result<InviteResponse, HttpError> {
+        let path = format!("invites/{code}");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
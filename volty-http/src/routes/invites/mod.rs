@@ -1 +1,3 @@
+pub mod invite_delete;
+pub mod invite_fetch;
 pub mod invite_join;
@@ -1,5 +1,13 @@
+pub mod auth;
 pub mod autumn;
+pub mod bots;
 pub mod channels;
+pub mod emojis;
 pub mod invites;
+pub mod onboard;
+pub mod push;
+pub mod safety;
 pub mod servers;
+pub mod sync;
 pub mod users;
+pub mod webhooks;
@@ -0,0 +1,2 @@
+pub mod onboard_complete;
+pub mod onboard_hello;
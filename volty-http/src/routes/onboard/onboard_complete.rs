@@ -0,0 +1,51 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::{users::user::User, util::regex::RE_USERNAME};
+
+use crate::{error::HttpError, Http};
+
+/// # New User Data
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct OnboardComplete {
+    /// New username which will be used to identify the user on the platform
+    #[validate(length(min = 2, max = 32), regex(path = *RE_USERNAME))]
+    username: String,
+}
+
+impl OnboardComplete {
+    pub fn new(username: impl std::fmt::Display) -> Self {
+        Self {
+            username: username.to_string(),
+        }
+    }
+}
+
+impl From<String> for OnboardComplete {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for OnboardComplete {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Http {
+    /// Claims a username for a new account.
+    ///
+    /// Fails with [ApiError::AlreadyOnboarded] if the account already has one.
+    ///
+    /// [ApiError::AlreadyOnboarded]: crate::ApiError::AlreadyOnboarded
+    pub async fn complete_onboarding(
+        &self,
+        data: impl Into<OnboardComplete>,
+    ) -> Result<User, HttpError> {
+        let data: OnboardComplete = data.into();
+        data.validate()?;
+        let request = self.request(Method::POST, "onboard/complete")?.json(&data);
+        self.send_request(request).await
+    }
+}
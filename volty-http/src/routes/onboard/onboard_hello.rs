@@ -0,0 +1,19 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// # Onboarding Status
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OnboardingStatus {
+    /// Whether onboarding is required
+    pub onboarding: bool,
+}
+
+impl Http {
+    /// Checks whether the current account still has to pick a username.
+    pub async fn fetch_onboarding(&self) -> Result<OnboardingStatus, HttpError> {
+        let request = self.request(Method::GET, "onboard/hello")?;
+        self.send_request(request).await
+    }
+}
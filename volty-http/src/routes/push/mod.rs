@@ -0,0 +1,2 @@
+pub mod push_subscribe;
+pub mod push_unsubscribe;
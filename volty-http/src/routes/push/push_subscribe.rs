@@ -0,0 +1,18 @@
+use reqwest::Method;
+use volty_types::types::push::WebPushSubscription;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Registers a Web Push subscription for the current session.
+    ///
+    /// The subscription should be created with the `vapid` key from [Http::api_info].
+    pub async fn subscribe_push(
+        &self,
+        data: impl Into<WebPushSubscription>,
+    ) -> Result<(), HttpError> {
+        let data: WebPushSubscription = data.into();
+        let request = self.request(Method::POST, "push/subscribe")?.json(&data);
+        self.send_request(request).await
+    }
+}
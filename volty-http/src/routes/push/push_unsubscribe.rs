@@ -0,0 +1,11 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Removes the current session's Web Push subscription.
+    pub async fn unsubscribe_push(&self) -> Result<(), HttpError> {
+        let request = self.request(Method::POST, "push/unsubscribe")?;
+        self.send_request(request).await
+    }
+}
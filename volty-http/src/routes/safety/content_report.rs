@@ -0,0 +1,171 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{error::HttpError, Http};
+
+/// Reason for reporting content (message or server)
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ContentReportReason {
+    /// No reason has been specified
+    #[default]
+    NoneSpecified,
+
+    /// Illegal content catch-all reason
+    Illegal,
+    /// Selling or facilitating use of drugs or other illegal goods
+    IllegalGoods,
+    /// Extortion or blackmail
+    IllegalExtortion,
+    /// Revenge or child pornography
+    IllegalPornography,
+    /// Illegal hacking activity
+    IllegalHacking,
+
+    /// Extreme violence, gore, or animal cruelty
+    ExtremeViolence,
+    /// Content that promotes harm to others / self
+    PromotesHarm,
+
+    /// Unsolicited advertisements
+    UnsolicitedSpam,
+    /// This is a raid
+    Raid,
+    /// Spam or platform abuse
+    SpamAbuse,
+
+    /// Scams or fraud
+    ScamsFraud,
+    /// Distribution of malware or malicious links
+    Malware,
+
+    /// Harassment or abuse targeted at another user
+    Harassment,
+}
+
+/// Reason for reporting a user
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum UserReportReason {
+    /// No reason has been specified
+    #[default]
+    NoneSpecified,
+
+    /// Unsolicited advertisements
+    UnsolicitedSpam,
+    /// User is sending spam or otherwise abusing the platform
+    SpamAbuse,
+    /// User's profile contains inappropriate content for a general audience
+    InappropriateProfile,
+    /// User is impersonating another user
+    Impersonation,
+    /// User is evading a ban
+    BanEvasion,
+    /// User is not of minimum age to use the platform
+    Underage,
+}
+
+/// The content being reported
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum ReportedContent {
+    /// Report a message
+    Message {
+        /// Id of the message
+        id: String,
+        /// Reason for reporting message
+        report_reason: ContentReportReason,
+    },
+    /// Report a server
+    Server {
+        /// Id of the server
+        id: String,
+        /// Reason for reporting server
+        report_reason: ContentReportReason,
+    },
+    /// Report a user
+    User {
+        /// Id of the user
+        id: String,
+        /// Reason for reporting a user
+        report_reason: UserReportReason,
+        /// Message context
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message_id: Option<String>,
+    },
+}
+
+/// # Report Data
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct ContentReport {
+    /// Content being reported
+    content: ReportedContent,
+
+    /// Additional report description
+    #[validate(length(min = 0, max = 1000))]
+    #[serde(default)]
+    additional_context: String,
+}
+
+impl ContentReport {
+    pub fn new(content: impl Into<ReportedContent>) -> Self {
+        Self {
+            content: content.into(),
+            additional_context: String::new(),
+        }
+    }
+
+    pub fn message(message_id: impl std::fmt::Display, reason: ContentReportReason) -> Self {
+        Self::new(ReportedContent::Message {
+            id: message_id.to_string(),
+            report_reason: reason,
+        })
+    }
+
+    pub fn server(server_id: impl std::fmt::Display, reason: ContentReportReason) -> Self {
+        Self::new(ReportedContent::Server {
+            id: server_id.to_string(),
+            report_reason: reason,
+        })
+    }
+
+    pub fn user(user_id: impl std::fmt::Display, reason: UserReportReason) -> Self {
+        Self::new(ReportedContent::User {
+            id: user_id.to_string(),
+            report_reason: reason,
+            message_id: None,
+        })
+    }
+
+    /// Message giving context to a user report, ignored for other reports.
+    pub fn message_id(mut self, message_id: impl std::fmt::Display) -> Self {
+        if let ReportedContent::User { message_id: m, .. } = &mut self.content {
+            *m = Some(message_id.to_string());
+        }
+        self
+    }
+
+    pub fn additional_context(mut self, context: impl std::fmt::Display) -> Self {
+        self.additional_context = context.to_string();
+        self
+    }
+}
+
+impl From<ReportedContent> for ContentReport {
+    fn from(value: ReportedContent) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Http {
+    /// Reports a message, server or user to the instance moderation team.
+    ///
+    /// Reporting yourself fails with [ApiError::CannotReportYourself].
+    ///
+    /// [ApiError::CannotReportYourself]: crate::ApiError::CannotReportYourself
+    pub async fn report_content(&self, data: impl Into<ContentReport>) -> Result<(), HttpError> {
+        let data: ContentReport = data.into();
+        data.validate()?;
+        let request = self.request(Method::POST, "safety/report")?.json(&data);
+        self.send_request(request).await
+    }
+}
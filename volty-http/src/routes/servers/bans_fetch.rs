@@ -0,0 +1,39 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::{media::attachment::File, servers::server_ban::ServerBan};
+
+use crate::{error::HttpError, Http};
+
+/// Just enough user information to list bans.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BannedUser {
+    /// Id of the banned user
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Username of the banned user
+    pub username: String,
+    /// Discriminator of the banned user
+    pub discriminator: String,
+    /// Avatar of the banned user
+    pub avatar: Option<File>,
+}
+
+/// # Ban List Result
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FetchBansResponse {
+    /// Users objects
+    pub users: Vec<BannedUser>,
+    /// Ban objects
+    pub bans: Vec<ServerBan>,
+}
+
+impl Http {
+    pub async fn fetch_bans(
+        &self,
+        server_id: impl std::fmt::Display,
+    ) -> Result<FetchBansResponse, HttpError> {
+        let path = format!("servers/{server_id}/bans");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,94 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::channels::channel::Channel;
+
+use crate::{error::HttpError, Http};
+
+/// Server Channel Type
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub enum ChannelType {
+    /// Text Channel
+    #[default]
+    Text,
+    /// Voice Channel
+    Voice,
+}
+
+/// # Channel Data
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct ChannelCreate {
+    /// Channel type
+    #[serde(rename = "type")]
+    channel_type: ChannelType,
+
+    /// Channel name
+    #[validate(length(min = 1, max = 32))]
+    name: String,
+
+    /// Channel description
+    #[validate(length(min = 0, max = 1024))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// Whether this channel is age restricted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+}
+
+impl ChannelCreate {
+    pub fn new(name: impl std::fmt::Display) -> Self {
+        Self {
+            channel_type: ChannelType::Text,
+            name: name.to_string(),
+            description: None,
+            nsfw: None,
+        }
+    }
+
+    pub fn channel_type(mut self, channel_type: impl Into<ChannelType>) -> Self {
+        self.channel_type = channel_type.into();
+        self
+    }
+
+    pub fn voice(mut self) -> Self {
+        self.channel_type = ChannelType::Voice;
+        self
+    }
+
+    pub fn description(mut self, description: impl std::fmt::Display) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn nsfw(mut self) -> Self {
+        self.nsfw = Some(true);
+        self
+    }
+}
+
+impl From<String> for ChannelCreate {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for ChannelCreate {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Http {
+    pub async fn create_channel(
+        &self,
+        server_id: impl std::fmt::Display,
+        data: impl Into<ChannelCreate>,
+    ) -> Result<Channel, HttpError> {
+        let data: ChannelCreate = data.into();
+        data.validate()?;
+        let path = format!("servers/{server_id}/channels");
+        let request = self.request(Method::POST, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
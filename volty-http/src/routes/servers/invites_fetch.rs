@@ -0,0 +1,15 @@
+use reqwest::Method;
+use volty_types::channels::channel_invite::Invite;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn fetch_invites(
+        &self,
+        server_id: impl std::fmt::Display,
+    ) -> Result<Vec<Invite>, HttpError> {
+        let path = format!("servers/{server_id}/invites");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
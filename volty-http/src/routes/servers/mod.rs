@@ -1,6 +1,18 @@
+pub mod bans_fetch;
+pub mod channel_create;
+pub mod invites_fetch;
 pub mod member_ban;
 pub mod member_edit;
 pub mod member_fetch;
+pub mod member_kick;
+pub mod member_unban;
 pub mod members_fetch;
+pub mod permissions_set;
+pub mod role_create;
+pub mod role_delete;
 pub mod role_edit;
+pub mod role_ranks_edit;
+pub mod server_ack;
 pub mod server_create;
+pub mod server_delete;
+pub mod server_edit;
@@ -0,0 +1,48 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::{permissions::Override, servers::server::Server};
+
+use crate::{error::HttpError, Http};
+
+/// # Permission Value
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct PermissionsSet {
+    /// Allow / deny values for the role in this server
+    permissions: Override,
+}
+
+/// # Permission Value
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct DefaultPermissionsSet {
+    /// Default member permission value
+    permissions: u64,
+}
+
+impl Http {
+    pub async fn set_role_permissions(
+        &self,
+        server_id: impl std::fmt::Display,
+        role_id: impl std::fmt::Display,
+        permissions: impl Into<Override>,
+    ) -> Result<Server, HttpError> {
+        let data = PermissionsSet {
+            permissions: permissions.into(),
+        };
+        let path = format!("servers/{server_id}/permissions/{role_id}");
+        let request = self.request(Method::PUT, &path)?.json(&data);
+        self.send_request(request).await
+    }
+
+    pub async fn set_server_default_permissions(
+        &self,
+        server_id: impl std::fmt::Display,
+        permissions: impl Into<u64>,
+    ) -> Result<Server, HttpError> {
+        let data = DefaultPermissionsSet {
+            permissions: permissions.into(),
+        };
+        let path = format!("servers/{server_id}/permissions/default");
+        let request = self.request(Method::PUT, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,69 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::servers::server::Role;
+
+use crate::{error::HttpError, Http};
+
+/// # Role Data
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct RoleCreate {
+    /// Role name
+    #[validate(length(min = 1, max = 32))]
+    name: String,
+
+    /// Ranking position
+    ///
+    /// Smaller values take priority.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rank: Option<i64>,
+}
+
+impl RoleCreate {
+    pub fn new(name: impl std::fmt::Display) -> Self {
+        Self {
+            name: name.to_string(),
+            rank: None,
+        }
+    }
+
+    pub fn rank(mut self, rank: impl Into<i64>) -> Self {
+        self.rank = Some(rank.into());
+        self
+    }
+}
+
+impl From<String> for RoleCreate {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for RoleCreate {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+/// # New Role Response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RoleCreateResponse {
+    /// Id of the role
+    pub id: String,
+    /// New role
+    pub role: Role,
+}
+
+impl Http {
+    pub async fn create_role(
+        &self,
+        server_id: impl std::fmt::Display,
+        data: impl Into<RoleCreate>,
+    ) -> Result<RoleCreateResponse, HttpError> {
+        let data: RoleCreate = data.into();
+        data.validate()?;
+        let path = format!("servers/{server_id}/roles");
+        let request = self.request(Method::POST, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
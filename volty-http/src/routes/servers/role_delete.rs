@@ -0,0 +1,15 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn delete_role(
+        &self,
+        server_id: impl std::fmt::Display,
+        role_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("servers/{server_id}/roles/{role_id}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
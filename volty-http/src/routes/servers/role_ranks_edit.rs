@@ -0,0 +1,28 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::servers::server::Server;
+
+use crate::{error::HttpError, Http};
+
+/// # Role Ranks
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RoleRanksEdit {
+    /// Role ids ordered by rank, highest priority first
+    ranks: Vec<String>,
+}
+
+impl Http {
+    /// Reorders all roles of a server, highest priority first.
+    pub async fn edit_role_ranks<S: std::fmt::Display>(
+        &self,
+        server_id: impl std::fmt::Display,
+        ranks: impl IntoIterator<Item = S>,
+    ) -> Result<Server, HttpError> {
+        let data = RoleRanksEdit {
+            ranks: ranks.into_iter().map(|s| s.to_string()).collect(),
+        };
+        let path = format!("servers/{server_id}/roles/ranks");
+        let request = self.request(Method::PATCH, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
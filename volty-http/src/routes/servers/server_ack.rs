@@ -0,0 +1,12 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Marks all channels in a server as read.
+    pub async fn ack_server(&self, server_id: impl std::fmt::Display) -> Result<(), HttpError> {
+        let path = format!("servers/{server_id}/ack");
+        let request = self.request(Method::PUT, &path)?;
+        self.send_request(request).await
+    }
+}
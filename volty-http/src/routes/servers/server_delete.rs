@@ -0,0 +1,35 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// # Query Parameters
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct ServerDelete {
+    /// Whether to not send a leave message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    leave_silently: Option<bool>,
+}
+
+impl Http {
+    /// Deletes a server we own.
+    pub async fn delete_server(&self, server_id: impl std::fmt::Display) -> Result<(), HttpError> {
+        let path = format!("servers/{server_id}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+
+    /// Leaves a server we don't own.
+    pub async fn leave_server(
+        &self,
+        server_id: impl std::fmt::Display,
+        silently: bool,
+    ) -> Result<(), HttpError> {
+        let data = ServerDelete {
+            leave_silently: Some(silently),
+        };
+        let path = format!("servers/{server_id}");
+        let request = self.request(Method::DELETE, &path)?.query(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,134 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::servers::server::{Category, FieldsServer, Server, SystemMessageChannels};
+
+use crate::{error::HttpError, Http};
+
+/// # Server Data
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct ServerEdit {
+    /// Server name
+    #[validate(length(min = 1, max = 32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// Server description
+    #[validate(length(min = 0, max = 1024))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// Attachment Id for icon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+
+    /// Attachment Id for banner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    banner: Option<String>,
+
+    /// Category structure for server
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<Category>>,
+
+    /// System message configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_messages: Option<SystemMessageChannels>,
+
+    /// Whether this server is age-restricted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+
+    /// Whether this server is public and should show up on [Revolt Discover](https://rvlt.gg)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discoverable: Option<bool>,
+
+    /// Whether analytics should be collected for this server
+    ///
+    /// Must be enabled in order to show up on [Revolt Discover](https://rvlt.gg).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analytics: Option<bool>,
+
+    /// Fields to remove from server object
+    #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove: Option<Vec<FieldsServer>>,
+}
+
+impl ServerEdit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl std::fmt::Display) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl std::fmt::Display) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn icon(mut self, icon: impl std::fmt::Display) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+
+    pub fn banner(mut self, banner: impl std::fmt::Display) -> Self {
+        self.banner = Some(banner.to_string());
+        self
+    }
+
+    pub fn categories<C: Into<Category>>(
+        mut self,
+        categories: impl IntoIterator<Item = C>,
+    ) -> Self {
+        self.categories = Some(categories.into_iter().map(|c| c.into()).collect());
+        self
+    }
+
+    pub fn system_messages(mut self, system_messages: impl Into<SystemMessageChannels>) -> Self {
+        self.system_messages = Some(system_messages.into());
+        self
+    }
+
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+        self
+    }
+
+    pub fn discoverable(mut self, discoverable: bool) -> Self {
+        self.discoverable = Some(discoverable);
+        self
+    }
+
+    pub fn analytics(mut self, analytics: bool) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
+    pub fn remove(mut self, remove: impl Into<FieldsServer>) -> Self {
+        let remove = remove.into();
+        if let Some(r) = &mut self.remove {
+            r.push(remove);
+        } else {
+            self.remove = Some(vec![remove])
+        }
+        self
+    }
+}
+
+impl Http {
+    pub async fn edit_server(
+        &self,
+        server_id: impl std::fmt::Display,
+        data: impl Into<ServerEdit>,
+    ) -> Result<Server, HttpError> {
+        let data: ServerEdit = data.into();
+        data.validate()?;
+        let path = format!("servers/{server_id}");
+        let request = self.request(Method::PATCH, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod settings_fetch;
+pub mod settings_set;
+pub mod unreads_fetch;
@@ -0,0 +1,45 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use volty_types::users::user_settings::UserSettings;
+
+use crate::{error::HttpError, Http};
+
+/// # Fetch Options
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SettingsFetch {
+    /// Keys to fetch
+    keys: Vec<String>,
+}
+
+impl SettingsFetch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn key(mut self, key: impl std::fmt::Display) -> Self {
+        self.keys.push(key.to_string());
+        self
+    }
+}
+
+impl<T: std::fmt::Display> FromIterator<T> for SettingsFetch {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            keys: iter.into_iter().map(|k| k.to_string()).collect(),
+        }
+    }
+}
+
+impl Http {
+    /// Fetches the given settings keys along with their revision timestamps.
+    pub async fn fetch_settings(
+        &self,
+        data: impl Into<SettingsFetch>,
+    ) -> Result<UserSettings, HttpError> {
+        let data: SettingsFetch = data.into();
+        let request = self
+            .request(Method::POST, "sync/settings/fetch")?
+            .json(&data);
+        self.send_request(request).await
+    }
+}
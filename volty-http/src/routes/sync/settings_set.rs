@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// # Query Parameters
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct SettingsSetQuery {
+    /// Timestamp of settings change
+    ///
+    /// Used to avoid feedback loops.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<i64>,
+}
+
+/// # Settings Data
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SettingsSet {
+    #[serde(skip)]
+    timestamp: Option<i64>,
+
+    /// Serialised data (in JSON format) for each key
+    #[serde(flatten)]
+    settings: HashMap<String, String>,
+}
+
+impl SettingsSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Revision timestamp for this change, defaults to the current time.
+    pub fn timestamp(mut self, timestamp: impl Into<i64>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn set(mut self, key: impl std::fmt::Display, value: impl std::fmt::Display) -> Self {
+        self.settings.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+impl Http {
+    pub async fn set_settings(&self, data: impl Into<SettingsSet>) -> Result<(), HttpError> {
+        let data: SettingsSet = data.into();
+        let query = SettingsSetQuery {
+            timestamp: data.timestamp,
+        };
+        let request = self
+            .request(Method::POST, "sync/settings/set")?
+            .query(&query)
+            .json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,11 @@
+use reqwest::Method;
+use volty_types::channels::channel_unread::ChannelUnread;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn fetch_unreads(&self) -> Result<Vec<ChannelUnread>, HttpError> {
+        let request = self.request(Method::GET, "sync/unreads")?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,15 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Fetches the PNG bytes of a user's generated default avatar.
+    pub async fn fetch_default_avatar(
+        &self,
+        user_id: impl std::fmt::Display,
+    ) -> Result<Vec<u8>, HttpError> {
+        let path = format!("users/{user_id}/default_avatar");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request_bytes(request).await
+    }
+}
@@ -0,0 +1,22 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// # Flag Response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FlagResponse {
+    /// Flags
+    pub flags: i32,
+}
+
+impl Http {
+    pub async fn fetch_user_flags(
+        &self,
+        user_id: impl std::fmt::Display,
+    ) -> Result<FlagResponse, HttpError> {
+        let path = format!("users/{user_id}/flags");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,15 @@
+use reqwest::Method;
+use volty_types::users::user::User;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn accept_friend_request(
+        &self,
+        user_id: impl std::fmt::Display,
+    ) -> Result<User, HttpError> {
+        let path = format!("users/{user_id}/friend");
+        let request = self.request(Method::PUT, &path)?;
+        self.send_request(request).await
+    }
+}
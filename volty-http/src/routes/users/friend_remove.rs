@@ -0,0 +1,13 @@
+use reqwest::Method;
+use volty_types::users::user::User;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    /// Denies an incoming friend request or removes an existing friend.
+    pub async fn remove_friend(&self, user_id: impl std::fmt::Display) -> Result<User, HttpError> {
+        let path = format!("users/{user_id}/friend");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
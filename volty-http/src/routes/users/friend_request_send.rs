@@ -0,0 +1,56 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::users::user::User;
+
+use crate::{error::HttpError, Http};
+
+/// # Friend Request
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct FriendRequest {
+    /// Username and discriminator combo separated by #
+    #[validate(length(min = 1))]
+    username: String,
+}
+
+impl FriendRequest {
+    /// Takes a `username#discriminator` combo.
+    pub fn new(username: impl std::fmt::Display) -> Self {
+        Self {
+            username: username.to_string(),
+        }
+    }
+
+    pub fn with_discriminator(
+        username: impl std::fmt::Display,
+        discriminator: impl std::fmt::Display,
+    ) -> Self {
+        Self {
+            username: format!("{username}#{discriminator}"),
+        }
+    }
+}
+
+impl From<String> for FriendRequest {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for FriendRequest {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Http {
+    pub async fn send_friend_request(
+        &self,
+        data: impl Into<FriendRequest>,
+    ) -> Result<User, HttpError> {
+        let data: FriendRequest = data.into();
+        data.validate()?;
+        let request = self.request(Method::POST, "users/friend")?.json(&data);
+        self.send_request(request).await
+    }
+}
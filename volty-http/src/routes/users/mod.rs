@@ -1,3 +1,13 @@
+pub mod default_avatar_fetch;
+pub mod flags_fetch;
+pub mod friend_accept;
+pub mod friend_remove;
+pub mod friend_request_send;
+pub mod mutual_fetch;
 pub mod open_dm;
+pub mod profile_fetch;
+pub mod user_block;
 pub mod user_edit;
 pub mod user_fetch;
+pub mod user_unblock;
+pub mod username_change;
@@ -0,0 +1,24 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::HttpError, Http};
+
+/// # Mutual Friends and Servers
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MutualResponse {
+    /// Array of mutual user IDs that both users are friends with
+    pub users: Vec<String>,
+    /// Array of mutual server IDs that both users are in
+    pub servers: Vec<String>,
+}
+
+impl Http {
+    pub async fn fetch_mutual(
+        &self,
+        user_id: impl std::fmt::Display,
+    ) -> Result<MutualResponse, HttpError> {
+        let path = format!("users/{user_id}/mutual");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
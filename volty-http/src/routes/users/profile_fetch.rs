@@ -0,0 +1,15 @@
+use reqwest::Method;
+use volty_types::users::user::UserProfile;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn fetch_user_profile(
+        &self,
+        user_id: impl std::fmt::Display,
+    ) -> Result<UserProfile, HttpError> {
+        let path = format!("users/{user_id}/profile");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
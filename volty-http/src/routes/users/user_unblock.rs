@@ -0,0 +1,12 @@
+use reqwest::Method;
+use volty_types::users::user::User;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn unblock_user(&self, user_id: impl std::fmt::Display) -> Result<User, HttpError> {
+        let path = format!("users/{user_id}/block");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,47 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use volty_types::{users::user::User, util::regex::RE_USERNAME};
+
+use crate::{error::HttpError, Http};
+
+/// # Username Information
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct UsernameChange {
+    /// New username
+    #[validate(length(min = 2, max = 32), regex(path = *RE_USERNAME))]
+    username: String,
+
+    /// Current account password
+    #[validate(length(min = 8, max = 1024))]
+    password: String,
+}
+
+impl UsernameChange {
+    pub fn new(username: impl std::fmt::Display, password: impl std::fmt::Display) -> Self {
+        Self {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+}
+
+impl Http {
+    /// Changes the current user's username.
+    ///
+    /// Changing it too often fails with [ApiError::DiscriminatorChangeRatelimited],
+    /// as a new discriminator may have to be assigned.
+    ///
+    /// [ApiError::DiscriminatorChangeRatelimited]: crate::ApiError::DiscriminatorChangeRatelimited
+    pub async fn change_username(
+        &self,
+        data: impl Into<UsernameChange>,
+    ) -> Result<User, HttpError> {
+        let data: UsernameChange = data.into();
+        data.validate()?;
+        let request = self
+            .request(Method::PATCH, "users/@me/username")?
+            .json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,4 @@
+pub mod webhook_delete;
+pub mod webhook_edit;
+pub mod webhook_execute;
+pub mod webhook_fetch;
@@ -0,0 +1,24 @@
+use reqwest::Method;
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn delete_webhook(
+        &self,
+        webhook_id: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("webhooks/{webhook_id}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+
+    pub async fn delete_webhook_with_token(
+        &self,
+        webhook_id: impl std::fmt::Display,
+        token: impl std::fmt::Display,
+    ) -> Result<(), HttpError> {
+        let path = format!("webhooks/{webhook_id}/{token}");
+        let request = self.request(Method::DELETE, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,32 @@
+use reqwest::Method;
+use validator::Validate;
+use volty_types::channels::webhook::{DataEditWebhook, Webhook};
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn edit_webhook(
+        &self,
+        webhook_id: impl std::fmt::Display,
+        data: impl Into<DataEditWebhook>,
+    ) -> Result<Webhook, HttpError> {
+        let data: DataEditWebhook = data.into();
+        data.validate()?;
+        let path = format!("webhooks/{webhook_id}");
+        let request = self.request(Method::PATCH, &path)?.json(&data);
+        self.send_request(request).await
+    }
+
+    pub async fn edit_webhook_with_token(
+        &self,
+        webhook_id: impl std::fmt::Display,
+        token: impl std::fmt::Display,
+        data: impl Into<DataEditWebhook>,
+    ) -> Result<Webhook, HttpError> {
+        let data: DataEditWebhook = data.into();
+        data.validate()?;
+        let path = format!("webhooks/{webhook_id}/{token}");
+        let request = self.request(Method::PATCH, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,20 @@
+use reqwest::Method;
+use validator::Validate;
+use volty_types::channels::message::Message;
+
+use crate::{error::HttpError, routes::channels::message_send::SendableMessage, Http};
+
+impl Http {
+    pub async fn execute_webhook(
+        &self,
+        webhook_id: impl std::fmt::Display,
+        token: impl std::fmt::Display,
+        message: impl Into<SendableMessage>,
+    ) -> Result<Message, HttpError> {
+        let data: SendableMessage = message.into();
+        data.validate()?;
+        let path = format!("webhooks/{webhook_id}/{token}");
+        let request = self.request(Method::POST, &path)?.json(&data);
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,25 @@
+use reqwest::Method;
+use volty_types::channels::webhook::{ResponseWebhook, Webhook};
+
+use crate::{error::HttpError, Http};
+
+impl Http {
+    pub async fn fetch_webhook(
+        &self,
+        webhook_id: impl std::fmt::Display,
+    ) -> Result<ResponseWebhook, HttpError> {
+        let path = format!("webhooks/{webhook_id}");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+
+    pub async fn fetch_webhook_with_token(
+        &self,
+        webhook_id: impl std::fmt::Display,
+        token: impl std::fmt::Display,
+    ) -> Result<Webhook, HttpError> {
+        let path = format!("webhooks/{webhook_id}/{token}");
+        let request = self.request(Method::GET, &path)?;
+        self.send_request(request).await
+    }
+}
@@ -0,0 +1,98 @@
+use volty_types::channels::{
+    message::Message,
+    webhook::{DataEditWebhook, Webhook},
+};
+
+use crate::{
+    DEFAULT_API_URL, Http,
+    error::HttpError,
+    retry_after,
+    routes::{
+        autumn::upload_file::{Tag, UploadFile, UploadResponse},
+        channels::message_send::SendableMessage,
+    },
+};
+
+/// Client for a single webhook, authenticated only by its id and token.
+///
+/// Each client keeps its own rate limit buckets, separate from any [Http],
+/// and waits out rate limits instead of failing. Clone it to share them.
+///
+/// The API has no routes for editing or deleting messages sent by a webhook,
+/// so only the webhook itself can be edited or deleted.
+#[derive(Clone)]
+pub struct WebhookClient {
+    http: Http,
+    webhook_id: String,
+    token: String,
+}
+
+impl WebhookClient {
+    pub fn new(webhook_id: impl std::fmt::Display, token: impl std::fmt::Display) -> Self {
+        Self::with_api_url(DEFAULT_API_URL, webhook_id, token)
+    }
+
+    pub fn with_api_url(
+        api_url: impl std::fmt::Display,
+        webhook_id: impl std::fmt::Display,
+        token: impl std::fmt::Display,
+    ) -> Self {
+        Self {
+            http: Http::unauthenticated(api_url),
+            webhook_id: webhook_id.to_string(),
+            token: token.to_string(),
+        }
+    }
+
+    /// Returns `None` if the webhook was fetched without its token.
+    pub fn from_webhook(api_url: impl std::fmt::Display, webhook: &Webhook) -> Option<Self> {
+        let token = webhook.token.as_ref()?;
+        Some(Self::with_api_url(api_url, &webhook.id, token))
+    }
+
+    pub fn webhook_id(&self) -> &str {
+        &self.webhook_id
+    }
+
+    pub async fn execute(&self, message: impl Into<SendableMessage>) -> Result<Message, HttpError> {
+        let message = message.into();
+        retry_after(|| {
+            self.http
+                .execute_webhook(&self.webhook_id, &self.token, message.clone())
+        })
+        .await
+    }
+
+    pub async fn fetch(&self) -> Result<Webhook, HttpError> {
+        retry_after(|| {
+            self.http
+                .fetch_webhook_with_token(&self.webhook_id, &self.token)
+        })
+        .await
+    }
+
+    pub async fn edit(&self, data: impl Into<DataEditWebhook>) -> Result<Webhook, HttpError> {
+        let data = data.into();
+        retry_after(|| {
+            self.http
+                .edit_webhook_with_token(&self.webhook_id, &self.token, data.clone())
+        })
+        .await
+    }
+
+    pub async fn delete(&self) -> Result<(), HttpError> {
+        retry_after(|| {
+            self.http
+                .delete_webhook_with_token(&self.webhook_id, &self.token)
+        })
+        .await
+    }
+
+    pub async fn upload_file(
+        &self,
+        tag: Tag,
+        file: UploadFile,
+    ) -> Result<UploadResponse, HttpError> {
+        self.http.upload_file(tag, file).await
+    }
+}
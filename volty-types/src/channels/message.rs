@@ -215,7 +215,7 @@ pub struct Message {
 }
 
 /// Sort used for retrieving messages
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub enum MessageSort {
     /// Sort by the most relevant messages
     #[default]
@@ -245,6 +245,14 @@ pub enum BulkMessageResponse {
     },
 }
 
+impl BulkMessageResponse {
+    pub fn messages(&self) -> &[Message] {
+        match self {
+            Self::JustMessages(messages) | Self::MessagesAndUsers { messages, .. } => messages,
+        }
+    }
+}
+
 /// # Appended Information
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AppendMessage {
@@ -269,3 +277,11 @@ pub enum MessageFlags {
 pub enum FieldsMessage {
     Pinned,
 }
+
+impl FieldsMessage {
+    pub fn remove(&self, message: &mut Message) {
+        match self {
+            FieldsMessage::Pinned => message.pinned = None,
+        }
+    }
+}
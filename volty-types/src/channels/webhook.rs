@@ -44,17 +44,20 @@ pub struct MessageWebhook {
 }
 
 /// New webhook information
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct DataEditWebhook {
     /// Webhook name
     #[validate(length(min = 1, max = 32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     /// Avatar ID
     #[validate(length(min = 1, max = 128))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar: Option<String>,
 
     /// Webhook permissions
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<u64>,
 
     /// Fields to remove from webhook
@@ -62,6 +65,32 @@ pub struct DataEditWebhook {
     pub remove: Vec<FieldsWebhook>,
 }
 
+impl DataEditWebhook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl std::fmt::Display) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn avatar(mut self, avatar: impl std::fmt::Display) -> Self {
+        self.avatar = Some(avatar.to_string());
+        self
+    }
+
+    pub fn permissions(mut self, permissions: impl Into<u64>) -> Self {
+        self.permissions = Some(permissions.into());
+        self
+    }
+
+    pub fn remove(mut self, field: impl Into<FieldsWebhook>) -> Self {
+        self.remove.push(field.into());
+        self
+    }
+}
+
 /// Webhook information
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ResponseWebhook {
@@ -94,9 +123,37 @@ pub struct CreateWebhookBody {
     pub name: String,
 
     #[validate(length(min = 1, max = 128))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar: Option<String>,
 }
 
+impl CreateWebhookBody {
+    pub fn new(name: impl std::fmt::Display) -> Self {
+        Self {
+            name: name.to_string(),
+            avatar: None,
+        }
+    }
+
+    /// Autumn id of an image uploaded with the `avatars` tag
+    pub fn avatar(mut self, avatar: impl std::fmt::Display) -> Self {
+        self.avatar = Some(avatar.to_string());
+        self
+    }
+}
+
+impl From<String> for CreateWebhookBody {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for CreateWebhookBody {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
 impl From<Webhook> for MessageWebhook {
     fn from(value: Webhook) -> Self {
         MessageWebhook {
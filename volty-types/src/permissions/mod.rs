@@ -15,7 +15,7 @@ use crate::servers::{server::Server, server_member::Member};
 pub struct PermissionValue(pub u64);
 
 /// Representation of a single permission override
-#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default)]
 pub struct Override {
     /// Allow bit flags
     allow: u64,
@@ -34,6 +34,26 @@ pub struct OverrideField {
 }
 
 impl Override {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow given permissions
+    pub fn allow(mut self, permission: impl Into<u64>) -> Self {
+        let permission = permission.into();
+        self.allow |= permission;
+        self.deny &= !permission;
+        self
+    }
+
+    /// Deny given permissions
+    pub fn deny(mut self, permission: impl Into<u64>) -> Self {
+        let permission = permission.into();
+        self.deny |= permission;
+        self.allow &= !permission;
+        self
+    }
+
     /// Into allows
     pub fn allows(&self) -> u64 {
         self.allow
@@ -45,6 +65,18 @@ impl Override {
     }
 }
 
+impl OverrideField {
+    /// Into allows
+    pub fn allows(&self) -> u64 {
+        self.a as u64
+    }
+
+    /// Into denies
+    pub fn denies(&self) -> u64 {
+        self.d as u64
+    }
+}
+
 impl PermissionValue {
     /// Apply a given override to this value
     pub fn apply(&mut self, v: Override) {
@@ -140,6 +140,48 @@ pub struct Metadata {
     colour: Option<String>,
 }
 
+impl Metadata {
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn original_url(&self) -> Option<&str> {
+        self.original_url.as_deref()
+    }
+
+    pub fn special(&self) -> Option<&Special> {
+        self.special.as_ref()
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn image(&self) -> Option<&Image> {
+        self.image.as_ref()
+    }
+
+    pub fn video(&self) -> Option<&Video> {
+        self.video.as_ref()
+    }
+
+    pub fn site_name(&self) -> Option<&str> {
+        self.site_name.as_deref()
+    }
+
+    pub fn icon_url(&self) -> Option<&str> {
+        self.icon_url.as_deref()
+    }
+
+    pub fn colour(&self) -> Option<&str> {
+        self.colour.as_deref()
+    }
+}
+
 /// Text Embed
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Text {
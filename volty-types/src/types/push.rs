@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 /// Push Notification
+///
+/// Web Push messages arrive encrypted (RFC 8291, `aes128gcm`) with the keys
+/// of the [WebPushSubscription]. Decrypting them is left to the receiver,
+/// the plaintext is this struct as JSON.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PushNotification {
     /// Known author name
@@ -19,3 +23,14 @@ pub struct PushNotification {
     /// URL to open when clicking notification
     pub url: String,
 }
+
+/// Web Push subscription
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebPushSubscription {
+    /// Push service endpoint
+    pub endpoint: String,
+    /// P-256 Diffie-Hellman public key, base64 encoded
+    pub p256dh: String,
+    /// Authentication secret, base64 encoded
+    pub auth: String,
+}
@@ -54,7 +54,7 @@ pub struct Bot {
 }
 
 /// Optional fields on bot object
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum FieldsBot {
     Token,
     InteractionsURL,
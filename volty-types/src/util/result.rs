@@ -67,8 +67,8 @@ pub enum Error {
 
     // ? General errors
     DatabaseError {
-        operation: &'static str,
-        with: &'static str,
+        operation: String,
+        with: String,
     },
     InternalError,
     InvalidOperation,
@@ -35,7 +35,7 @@ pub enum WebSocketError {
 }
 
 /// Untagged Error
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum ErrorEvent {
     Error(WebSocketError),
@@ -51,6 +51,11 @@ pub enum ServerMessage {
         v: Vec<ServerMessage>,
     },
 
+    /// Error occurred, the connection is usually closed afterwards
+    Error {
+        data: ErrorEvent,
+    },
+
     /// Successfully authenticated
     Authenticated,
 
@@ -5,12 +5,22 @@ use std::{
 };
 
 use async_trait::async_trait;
-use futures_util::Future;
+use futures_util::{
+    Future, StreamExt,
+    stream::{self, BoxStream},
+};
 use tokio::sync::{OnceCell, RwLock};
-use volty_http::{ApiError, Http, error::HttpError};
+use volty_http::{
+    ApiError, Http,
+    error::HttpError,
+    routes::channels::{group_create::GroupCreate, messages_fetch::MessagesFetch},
+};
 use volty_types::{
     RevoltConfig,
-    channels::{channel::Channel, message::Message},
+    channels::{
+        channel::Channel,
+        message::{BulkMessageResponse, Message},
+    },
     media::emoji::Emoji,
     permissions::{
         PermissionValue, calculate_dm_permissions, calculate_group_permissions,
@@ -190,6 +200,14 @@ impl InnerCache {
             .map_err(|e| (*e).clone())
     }
 
+    /// Inserts a user returned from the API, replacing any existing entry.
+    pub async fn insert_user(&self, user: User) {
+        if self.user_id.get() == Some(&user.id) {
+            self.user.write().await.replace(user.clone());
+        }
+        self.users.insert(user.id.clone(), user).await;
+    }
+
     pub async fn get_server(&self, server_id: &str) -> Option<Server> {
         self.servers.read().await.get(server_id).cloned()
     }
@@ -198,6 +216,32 @@ impl InnerCache {
         self.channels.read().await.get(channel_id).cloned()
     }
 
+    /// Inserts a channel returned from the API, replacing any existing entry.
+    pub async fn insert_channel(&self, channel: Channel) {
+        if let Channel::DirectMessage { id, recipients, .. } = &channel {
+            let user_id = self.user_id();
+            let other = recipients.iter().find(|&i| i != user_id).unwrap();
+            self.user_dms
+                .write()
+                .await
+                .insert(other.clone(), id.clone());
+        }
+        self.channels
+            .write()
+            .await
+            .insert(channel.id().to_string(), channel);
+    }
+
+    pub async fn create_group(
+        &self,
+        http: &Http,
+        data: impl Into<GroupCreate>,
+    ) -> Result<Channel, HttpError> {
+        let channel = http.create_group(data).await?;
+        self.insert_channel(channel.clone()).await;
+        Ok(channel)
+    }
+
     pub async fn fetch_dm(&self, http: &Http, user_id: &str) -> Result<Channel, HttpError> {
         if let Some(channel_id) = self.user_dms.read().await.get(user_id) {
             Ok(self.get_channel(channel_id).await.unwrap())
@@ -353,6 +397,51 @@ impl InnerCache {
             .await;
         Ok(message)
     }
+
+    /// Walks a channel's history, see [Http::messages_stream].
+    ///
+    /// Users and members included in each page are merged into the cache.
+    pub fn messages_stream<'a>(
+        &'a self,
+        http: &Http,
+        channel_id: &str,
+        data: impl Into<MessagesFetch>,
+    ) -> BoxStream<'a, Result<Message, HttpError>> {
+        http.messages_stream(channel_id, data)
+            .then(move |page| async move {
+                match page {
+                    Ok(page) => self
+                        .merge_messages(page)
+                        .await
+                        .into_iter()
+                        .map(Ok)
+                        .collect(),
+                    Err(e) => vec![Err(e)],
+                }
+            })
+            .flat_map(stream::iter)
+            .boxed()
+    }
+
+    async fn merge_messages(&self, response: BulkMessageResponse) -> Vec<Message> {
+        let (messages, users, members) = match response {
+            BulkMessageResponse::JustMessages(messages) => return messages,
+            BulkMessageResponse::MessagesAndUsers {
+                messages,
+                users,
+                members,
+            } => (messages, users, members),
+        };
+        for user in users {
+            self.insert_user(user).await;
+        }
+        let mut s_members = self.members.write().await;
+        for member in members.unwrap_or_default() {
+            let members = s_members.entry(member.id.server.clone()).or_default();
+            members.insert(member.id.user.clone(), member).await;
+        }
+        messages
+    }
 }
 
 #[async_trait]
@@ -379,6 +468,7 @@ impl UpdateCache for InnerCache {
                     self.update(message).await;
                 }
             }
+            Error { .. } => {}
             Authenticated => {}
             Ready {
                 users,
@@ -472,9 +562,12 @@ impl UpdateCache for InnerCache {
                 id,
                 channel_id: _,
                 data,
-                clear: _,
+                clear,
             } => {
                 if let Some(mut message) = self.messages.get(&id).await {
+                    for field in clear {
+                        field.remove(&mut message);
+                    }
                     message.apply_options(data);
                     self.messages.insert(id, message).await;
                 }
@@ -546,18 +639,7 @@ impl UpdateCache for InnerCache {
             }
 
             ChannelCreate(channel) => {
-                if let Channel::DirectMessage { id, recipients, .. } = &channel {
-                    let user_id = self.user_id();
-                    let other = recipients.iter().find(|&i| i != user_id).unwrap();
-                    self.user_dms
-                        .write()
-                        .await
-                        .insert(other.clone(), id.clone());
-                }
-                self.channels
-                    .write()
-                    .await
-                    .insert(channel.id().to_string(), channel);
+                self.insert_channel(channel).await;
             }
             ChannelUpdate { id, data, clear } => {
                 if let Some(channel) = self.channels.write().await.get_mut(&id) {
@@ -15,12 +15,16 @@ use volty_types::{
         user::{FieldsUser, PartialUser, User},
         user_settings::UserSettings,
     },
-    ws::{common::Ping, server::ServerMessage},
+    ws::{
+        common::Ping,
+        server::{ErrorEvent, ServerMessage},
+    },
 };
 
 #[allow(unused_variables)]
 #[async_trait]
 pub trait RawHandler {
+    async fn on_error(&self, error: ErrorEvent) {}
     async fn on_authenticated(&self) {}
 
     async fn on_ready(
@@ -130,6 +134,9 @@ pub trait RawHandler {
                     self.on_event(event).await;
                 }
             }
+            Error { data } => {
+                self.on_error(data).await;
+            }
             Authenticated => {
                 self.on_authenticated().await;
             }
@@ -8,12 +8,16 @@ use std::time::{Duration, Instant};
 use tokio::net::TcpStream;
 use tokio::select;
 use tokio::sync::Mutex;
-use tokio::time::sleep;
+use tokio::time::{sleep, timeout};
 use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
 use tokio_tungstenite::tungstenite::{self, Bytes};
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async_with_config};
 
-use volty_types::ws::{client::ClientMessage, common::Ping, server::ServerMessage};
+use volty_types::ws::{
+    client::ClientMessage,
+    common::Ping,
+    server::{ErrorEvent, ServerMessage, WebSocketError},
+};
 
 mod cache;
 pub use cache::{Cache, UpdateCache};
@@ -77,10 +81,16 @@ pub struct InnerWebSocket {
     tx: Mutex<WsTX>,
     rx: Mutex<WsRX>,
     last_message: Mutex<Instant>,
+    /// First message, read while connecting
+    pending: Mutex<Option<ServerMessage>>,
 }
 
 impl WebSocket {
-    pub async fn connect(token: impl std::fmt::Display) -> Self {
+    /// Fails if the server rejects the token, e.g. with
+    /// [WebSocketError::OnboardingNotFinished] for accounts without a username,
+    /// or with [WebSocketError::LabelMe] if the connection closes before any reply.
+    /// If the server doesn't answer within 30 seconds the socket is returned anyway.
+    pub async fn connect(token: impl std::fmt::Display) -> Result<Self, ErrorEvent> {
         const DEFAULT_WS_URL: &str = "wss://events.stoat.chat";
         Self::connect_with_url(DEFAULT_WS_URL, token).await
     }
@@ -88,7 +98,7 @@ impl WebSocket {
     pub async fn connect_with_url(
         ws_url: impl std::fmt::Display,
         token: impl std::fmt::Display,
-    ) -> Self {
+    ) -> Result<Self, ErrorEvent> {
         let mut url = ws_url.to_string();
         // wss://events.stoat.chat -> wss://events.stoat.chat/
         // wss://stoat.chat/events -> unchanged
@@ -96,16 +106,52 @@ impl WebSocket {
             url.push('/');
         }
         write!(&mut url, "?format=msgpack&token={}", &token).unwrap();
-        let (tx, rx) = retrying_connect(&url).await;
+        let (tx, mut rx) = retrying_connect(&url).await;
+        // The server either authenticates us or explains why not and closes.
+        let first = timeout(Duration::from_millis(HEARTBEAT as u64), async {
+            loop {
+                match rx.next().await {
+                    Some(Ok(tungstenite::Message::Close(frame))) => {
+                        log::error!("Connect: closed {:?}", frame);
+                        break None;
+                    }
+                    Some(Ok(message)) => {
+                        if let Some(msg) = parse_msg(message) {
+                            break Some(msg);
+                        }
+                    }
+                    Some(Err(e)) => {
+                        log::error!("Connect: {:?}", e);
+                        break None;
+                    }
+                    None => {
+                        log::error!("Connect: stream ended");
+                        break None;
+                    }
+                }
+            }
+        })
+        .await;
+        let pending = match first {
+            Ok(Some(ServerMessage::Error { data })) => return Err(data),
+            Ok(Some(msg)) => Some(msg),
+            // Dropped without telling us why
+            Ok(None) => return Err(ErrorEvent::Error(WebSocketError::LabelMe)),
+            Err(_) => {
+                log::warn!("No response from server after connecting");
+                None
+            }
+        };
         let inner = InnerWebSocket {
             url,
             tx: Mutex::new(tx),
             rx: Mutex::new(rx),
             last_message: Mutex::new(Instant::now()),
+            pending: Mutex::new(pending),
         };
-        Self {
+        Ok(Self {
             inner: Arc::new(inner),
-        }
+        })
     }
 
     async fn update_last_message(&self) {
@@ -140,7 +186,16 @@ impl WebSocket {
         }
     }
 
+    /// Errors sent by the server are returned as [ServerMessage::Error],
+    /// the server closes the connection after sending one.
+    ///
+    /// Closed connections are always reopened, so if the token stops working
+    /// mid-session every following reconnect yields another error.
+    /// Stop calling `next` once an error like [WebSocketError::InvalidSession] arrives.
     pub async fn next(&self) -> ServerMessage {
+        if let Some(msg) = self.pending.lock().await.take() {
+            return msg;
+        }
         loop {
             let last_ms = self.last_message.lock().await.elapsed().as_millis();
             if last_ms >= HEARTBEAT * 2 {